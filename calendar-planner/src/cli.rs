@@ -0,0 +1,603 @@
+use std::io::{self, Write};
+use std::path::PathBuf;
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use chrono_tz::Tz;
+use clap::{Args, Parser, Subcommand};
+use crate::config::Config;
+use crate::model::conflict::{find_conflicts, occurrence_key, overlapping};
+use crate::model::filter::{occurrences_between, page_around, visible_occurrences, EventFilter, ViewMode};
+use crate::model::free::free_slots;
+use crate::model::query::FilterQuery;
+use crate::model::search::SearchQuery;
+use crate::model::series::{delete_occurrence, edit_occurrence, EditScope};
+use crate::model::Event;
+use crate::ical;
+use crate::storage::Storage;
+use crate::utils::parse::{
+    end_after, format_duration, format_event_time, format_original_time, format_recurrence, format_slot, format_until,
+    parse_count, parse_date, parse_days, parse_duration, parse_recurrence, parse_start, parse_start_in,
+    parse_text, parse_time_zone, parse_until, parse_url, parse_within, DATE_TIME_FORMAT,
+};
+use crate::utils::time::{local_time_zone, resolve_in, start_of_day, wall_clock};
+
+#[derive(Parser)]
+#[command(name = "calendar-planner", version, about = "Planer wydarzeń w terminalu")]
+pub struct Cli {
+    /// Plik z wydarzeniami (domyślnie $CALENDAR_PLANNER_FILE lub katalog danych użytkownika)
+    #[arg(long, global = true)]
+    pub file: Option<PathBuf>,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Uruchamia interfejs tekstowy (domyślnie)
+    Tui,
+    /// Dodaje nowe wydarzenie
+    Add(AddArgs),
+    /// Wyświetla wystąpienia wydarzeń
+    List(ListArgs),
+    /// Wyszukuje wolne terminy w godzinach pracy
+    Free(FreeArgs),
+    /// Pokazuje szczegóły wydarzenia
+    Show {
+        /// Identyfikator wydarzenia (lub jego początek) z polecenia `list`
+        id: String,
+    },
+    /// Zmienia wybrane pola wydarzenia
+    Edit(EditArgs),
+    /// Usuwa wydarzenie lub wybrane wystąpienia wydarzenia cyklicznego
+    Delete {
+        /// Identyfikator wydarzenia (lub jego początek) z polecenia `list`
+        id: String,
+        #[command(flatten)]
+        occurrence: OccurrenceArgs,
+    },
+    /// Eksportuje wydarzenia do formatu iCalendar (.ics)
+    Export {
+        /// Plik docelowy (domyślnie standardowe wyjście)
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Importuje wydarzenia z pliku iCalendar (.ics), pomijając duplikaty
+    Import {
+        /// Plik źródłowy
+        source: PathBuf,
+    },
+    /// Wyświetla, zapisuje lub usuwa nazwane filtry
+    Filter {
+        /// Nazwa filtra (bez nazwy wyświetla listę zapisanych filtrów)
+        name: Option<String>,
+        /// Zapytanie zapisywane pod podaną nazwą, np. "color:red recurring:yes duration>2h"
+        #[arg(allow_hyphen_values = true)]
+        query: Option<String>,
+        /// Usuwa filtr o podanej nazwie
+        #[arg(long, requires = "name", conflicts_with = "query")]
+        delete: bool,
+    },
+    /// Wyświetla kopie zapasowe lub przywraca wskazaną kopię
+    Restore {
+        /// Numer kopii do przywrócenia (bez numeru wyświetla listę kopii)
+        number: Option<usize>,
+    },
+}
+
+#[derive(Args)]
+pub struct AddArgs {
+    /// Tytuł wydarzenia
+    #[arg(long)]
+    title: String,
+    /// Data rozpoczęcia w strefie wydarzenia (RRRR-MM-DD HH:MM, przy zmianie czasu z przesunięciem, np. +01:00)
+    /// lub sama data (RRRR-MM-DD) dla wydarzeń całodniowych
+    #[arg(long)]
+    start: String,
+    /// Wydarzenie całodniowe (bez godzin)
+    #[arg(long)]
+    all_day: bool,
+    /// Liczba dni wydarzenia całodniowego
+    #[arg(long, value_parser = parse_days, requires = "all_day")]
+    days: Option<u32>,
+    /// Strefa czasowa IANA, np. Europe/Warsaw (domyślnie strefa systemu, pusty napis oznacza czas lokalny)
+    #[arg(long = "tz")]
+    time_zone: Option<String>,
+    /// Czas trwania (np. 1.5 lub 1:30)
+    #[arg(long, value_parser = parse_duration, required_unless_present = "all_day", conflicts_with = "all_day")]
+    duration: Option<Duration>,
+    /// Kolor wydarzenia
+    #[arg(long)]
+    color: Option<String>,
+    /// Opis wydarzenia
+    #[arg(long)]
+    description: Option<String>,
+    /// Miejsce wydarzenia
+    #[arg(long)]
+    location: Option<String>,
+    /// Adres URL związany z wydarzeniem, np. link do spotkania
+    #[arg(long)]
+    url: Option<String>,
+    /// Powtarzanie, np. weekly/3, "weekly mo,we,fr", "monthly -1th", "monthly 31 clamp" lub reguła RRULE
+    ///
+    /// Brakujące dni (np. 31 w kwietniu) są domyślnie pomijane (skip); clamp przesuwa je na ostatni dzień
+    /// miesiąca, a roll na pierwszy dzień następnego.
+    #[arg(long)]
+    recurrence: Option<String>,
+    /// Ostatni dzień powtarzania (RRRR-MM-DD lub RRRR-MM-DD HH:MM)
+    #[arg(long)]
+    until: Option<String>,
+    /// Maksymalna liczba wystąpień
+    #[arg(long)]
+    count: Option<String>,
+}
+
+#[derive(Args)]
+pub struct EditArgs {
+    /// Identyfikator wydarzenia (lub jego początek) z polecenia `list`
+    id: String,
+    /// Nowy tytuł
+    #[arg(long)]
+    title: Option<String>,
+    /// Nowa data rozpoczęcia w strefie wydarzenia (RRRR-MM-DD HH:MM, dla wydarzeń całodniowych RRRR-MM-DD)
+    #[arg(long)]
+    start: Option<String>,
+    /// Zmienia wydarzenie w całodniowe (true) lub z godzinami (false)
+    #[arg(long)]
+    all_day: Option<bool>,
+    /// Nowa liczba dni wydarzenia całodniowego
+    #[arg(long, value_parser = parse_days)]
+    days: Option<u32>,
+    /// Nowa strefa czasowa IANA; bez --start godzina wydarzenia zostaje ta sama w nowej strefie
+    #[arg(long = "tz")]
+    time_zone: Option<String>,
+    /// Nowy czas trwania (np. 1.5 lub 1:30)
+    #[arg(long, value_parser = parse_duration)]
+    duration: Option<Duration>,
+    /// Nowy kolor (pusty napis usuwa kolor)
+    #[arg(long)]
+    color: Option<String>,
+    /// Nowy opis (pusty napis usuwa opis)
+    #[arg(long)]
+    description: Option<String>,
+    /// Nowe miejsce (pusty napis usuwa miejsce)
+    #[arg(long)]
+    location: Option<String>,
+    /// Nowy adres URL (pusty napis usuwa adres)
+    #[arg(long)]
+    url: Option<String>,
+    /// Nowe powtarzanie (np. "weekly mo,we,fr"; none usuwa powtarzanie)
+    #[arg(long)]
+    recurrence: Option<String>,
+    /// Nowy ostatni dzień powtarzania (pusty napis usuwa ograniczenie)
+    #[arg(long)]
+    until: Option<String>,
+    /// Nowa maksymalna liczba wystąpień (pusty napis usuwa ograniczenie)
+    #[arg(long)]
+    count: Option<String>,
+    #[command(flatten)]
+    occurrence: OccurrenceArgs,
+}
+
+#[derive(Args)]
+pub struct OccurrenceArgs {
+    /// Początek wystąpienia wydarzenia cyklicznego (RRRR-MM-DD HH:MM)
+    #[arg(long, value_parser = parse_start)]
+    occurrence: Option<DateTime<Local>>,
+    /// Zakres zmiany (domyślnie `single` dla --occurrence, w przeciwnym razie `all`)
+    #[arg(long, value_enum)]
+    scope: Option<EditScope>,
+}
+
+impl OccurrenceArgs {
+    fn resolve(&self, series: &Event) -> Result<(Event, EditScope), String> {
+        match (self.occurrence, self.scope) {
+            (None, None) | (None, Some(EditScope::All)) => Ok((series.clone(), EditScope::All)),
+            (None, Some(_)) => Err("Podaj --occurrence, aby zmienić pojedyncze wystąpienia.".to_string()),
+            (Some(start), scope) => {
+                let occurrence = series
+                    .find_occurrence(start)
+                    .ok_or_else(|| format!("Wydarzenie nie ma wystąpienia o godzinie {}.", start.format(DATE_TIME_FORMAT)))?;
+                match scope.unwrap_or(EditScope::Single) {
+                    EditScope::All => Ok((series.clone(), EditScope::All)),
+                    scope => Ok((occurrence, scope)),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct ListArgs {
+    /// Zakres widoku
+    #[arg(long, value_enum, default_value_t = ViewMode::All)]
+    view: ViewMode,
+    /// Dzień wyznaczający tydzień, miesiąc lub rok (RRRR-MM-DD, domyślnie dziś)
+    #[arg(long, value_parser = parse_date)]
+    date: Option<NaiveDate>,
+    /// Zakres widoku all: today, this-week, next-month itp. lub RRRR-MM-DD..RRRR-MM-DD (domyślnie 8 tygodni przed i po dniu --date)
+    #[arg(long)]
+    within: Option<String>,
+    /// Pokazuje tylko wydarzenia w tym kolorze
+    #[arg(long)]
+    color: Option<String>,
+    /// Pokazuje tylko nadchodzące wydarzenia
+    #[arg(long)]
+    upcoming: bool,
+    /// Pokazuje tylko wydarzenia zawierające frazę (bez rozróżniania wielkości liter i znaków diakrytycznych)
+    #[arg(long)]
+    search: Option<String>,
+    /// Zapytanie filtrujące, np. "color:red after:2025-06-01 title~projekt duration>2h"
+    ///
+    /// Pola: title, description, location, color, recurring, allday, upcoming, after, before, duration;
+    /// samo słowo szuka w tytule, opisie i miejscu. Warunki oddzielone
+    /// spacją muszą być spełnione jednocześnie; or łączy alternatywy, - neguje warunek, a nawiasy grupują.
+    #[arg(long, conflicts_with = "filter", allow_hyphen_values = true)]
+    query: Option<String>,
+    /// Nazwa filtra zapisanego poleceniem `filter`
+    #[arg(long)]
+    filter: Option<String>,
+    /// Sortuje malejąco
+    #[arg(long)]
+    desc: bool,
+}
+
+#[derive(Args)]
+pub struct FreeArgs {
+    /// Wymagany czas trwania (np. 1:30 lub 1.5)
+    #[arg(long, value_parser = parse_duration)]
+    duration: Duration,
+    /// Zakres wyszukiwania: today, tomorrow, this-week, next-week, this-month, next-month lub RRRR-MM-DD..RRRR-MM-DD
+    #[arg(long, default_value = "this-week")]
+    within: String,
+}
+
+pub fn run(
+    command: Command,
+    events: &mut Vec<Event>,
+    storage: &Storage,
+    config: &mut Config,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match command {
+        Command::Tui => unreachable!("interfejs tekstowy jest uruchamiany w main"),
+        Command::Add(args) => {
+            let time_zone = match args.time_zone {
+                Some(ref name) => parse_time_zone(name)?,
+                None => local_time_zone(),
+            };
+            let base = match args.duration {
+                Some(duration) if !args.all_day => {
+                    let start = parse_start_in(&args.start, time_zone)?;
+                    Event::new(args.title, start, end_after(start, duration)?)
+                }
+                _ => Event::new_all_day(args.title, time_zone, parse_date(&args.start)?, args.days.unwrap_or(1)),
+            };
+            let event = Event {
+                color: args.color.as_deref().and_then(parse_text),
+                description: args.description.as_deref().and_then(parse_text),
+                location: args.location.as_deref().and_then(parse_text),
+                url: parse_url(args.url.as_deref().unwrap_or_default())?,
+                time_zone,
+                recurrence: parse_recurrence(args.recurrence.as_deref().unwrap_or_default())?,
+                until: parse_until(args.until.as_deref().unwrap_or_default(), time_zone)?,
+                count: parse_count(args.count.as_deref().unwrap_or_default())?,
+                ..base
+            };
+            let id = event.id;
+            let conflicts = find_conflicts(events, &event, None, Local::now());
+            events.push(event);
+            storage.save(events)?;
+            println!("Dodano wydarzenie {}.", id);
+            warn_about_conflicts(&conflicts);
+        }
+        Command::List(args) => {
+            let now = Local::now();
+            let current_date = args.date.unwrap_or_else(|| now.date_naive());
+            let filter = EventFilter {
+                view_mode: args.view,
+                current_date,
+                show_only_upcoming: args.upcoming,
+                color: args.color,
+                query: match (args.query, args.filter) {
+                    (Some(query), _) => Some(FilterQuery::parse(&query)?),
+                    (None, Some(name)) => Some(config.named_filter(&name)?),
+                    (None, None) => None,
+                },
+                all_range: match args.within {
+                    Some(within) => parse_within(&within, now.date_naive())?,
+                    None => page_around(current_date),
+                },
+            };
+            let (from, to) = filter.window(now);
+            let occurrences = occurrences_between(events, from, to);
+            let conflicts = overlapping(&occurrences);
+            let query = args.search.as_deref().and_then(SearchQuery::new);
+            let mut out = io::stdout().lock();
+            for event in visible_occurrences(&occurrences, &filter, now, !args.desc)
+                .into_iter()
+                .filter(|e| query.as_ref().is_none_or(|q| q.matches(e)))
+            {
+                let line = format_occurrence(event, conflicts.contains(&occurrence_key(event)));
+                if let Err(err) = writeln!(out, "{}", line) {
+                    if err.kind() == io::ErrorKind::BrokenPipe {
+                        break;
+                    }
+                    return Err(err.into());
+                }
+            }
+        }
+        Command::Free(args) => {
+            let now = Local::now();
+            let (first, last) = parse_within(&args.within, now.date_naive())?;
+            let from = start_of_day(None, first).max(now);
+            let to = start_of_day(None, last + Duration::days(1));
+            let slots = free_slots(events, from, to, args.duration, &config.working_hours);
+            if slots.is_empty() {
+                println!("Brak wolnych terminów w podanym zakresie.");
+            }
+            for slot in slots {
+                println!("{}", format_slot(slot));
+            }
+        }
+        Command::Show { id } => {
+            let event = &events[find_event(events, &id)?];
+            println!("Id:           {}", event.id);
+            println!("Tytuł:        {}", event.title);
+            if event.all_day {
+                println!("Termin:       {}", format_event_time(event));
+                println!("Liczba dni:   {}", event.day_count());
+            } else {
+                println!("Początek:     {}", format_with_original(event.start, event.time_zone));
+                println!("Koniec:       {}", format_with_original(event.end, event.time_zone));
+                println!("Czas trwania: {}", format_duration(event.end - event.start));
+            }
+            println!("Strefa:       {}", event.time_zone.map_or("lokalna", |zone| zone.name()));
+            println!("Kolor:        {}", event.color.as_deref().unwrap_or("-"));
+            if let Some(ref location) = event.location {
+                println!("Miejsce:      {}", location);
+            }
+            if let Some(ref url) = event.url {
+                println!("URL:          {}", url);
+            }
+            if let Some(ref description) = event.description {
+                for (index, line) in description.lines().enumerate() {
+                    println!("{}{}", if index == 0 { "Opis:         " } else { "              " }, line);
+                }
+            }
+            match event.recurrence {
+                Some(ref recurrence) => println!("Powtarzanie:  {} ({})", format_recurrence(recurrence), recurrence),
+                None => println!("Powtarzanie:  brak"),
+            }
+            if let Some(until) = event.until {
+                println!("Do:           {}", format_until(until, event.time_zone));
+            }
+            if let Some(count) = event.count {
+                println!("Wystąpienia:  {}", count);
+            }
+            for exception in &event.exceptions {
+                println!("Pominięte:    {}", exception.format(DATE_TIME_FORMAT));
+            }
+            for o in &event.overrides {
+                println!(
+                    "Zmienione:    {} -> {} {}",
+                    o.original_start.format(DATE_TIME_FORMAT),
+                    o.start.format(DATE_TIME_FORMAT),
+                    o.title
+                );
+            }
+        }
+        Command::Edit(args) => {
+            let index = find_event(events, &args.id)?;
+            let (mut event, scope) = args.occurrence.resolve(&events[index])?;
+            let occurrence_start = event.occurrence_start();
+            let duration = args.duration.unwrap_or(event.end - event.start);
+
+            if let Some(title) = args.title {
+                event.title = title;
+            }
+            if let Some(time_zone) = args.time_zone {
+                let time_zone = parse_time_zone(&time_zone)?;
+                event.start = resolve_in(time_zone, wall_clock(event.time_zone, event.start));
+                event.time_zone = time_zone;
+            }
+            if args.all_day.unwrap_or(event.all_day) {
+                let first_day = match args.start {
+                    Some(ref start) => parse_date(start)?,
+                    None => event.start_date(),
+                };
+                let days = args.days.unwrap_or(if event.all_day { event.day_count() } else { 1 });
+                event.set_days(first_day, days);
+            } else {
+                if let Some(start) = args.start {
+                    event.start = parse_start_in(&start, event.time_zone)?;
+                }
+                event.all_day = false;
+                event.end = end_after(event.start, duration)?;
+            }
+            if let Some(color) = args.color {
+                event.color = parse_text(&color);
+            }
+            if let Some(description) = args.description {
+                event.description = parse_text(&description);
+            }
+            if let Some(location) = args.location {
+                event.location = parse_text(&location);
+            }
+            if let Some(url) = args.url {
+                event.url = parse_url(&url)?;
+            }
+            if let Some(recurrence) = args.recurrence {
+                event.recurrence = parse_recurrence(&recurrence)?;
+            }
+            if let Some(until) = args.until {
+                event.until = parse_until(&until, event.time_zone)?;
+            }
+            if let Some(count) = args.count {
+                event.count = parse_count(&count)?;
+            }
+
+            let series_id = events[index].id;
+            let conflicts = find_conflicts(events, &event, Some(series_id), Local::now());
+            let edited_id = edit_occurrence(events, series_id, occurrence_start, scope, event);
+            storage.save(events)?;
+            if let Some(edited_id) = edited_id {
+                println!("Zaktualizowano wydarzenie {}.", edited_id);
+            }
+            warn_about_conflicts(&conflicts);
+        }
+        Command::Delete { id, occurrence } => {
+            let index = find_event(events, &id)?;
+            let (target, scope) = occurrence.resolve(&events[index])?;
+            delete_occurrence(events, target.id, target.occurrence_start(), scope);
+            storage.save(events)?;
+            println!("Usunięto wydarzenie {} ({}).", target.id, scope.label().to_lowercase());
+        }
+        Command::Export { output } => match output {
+            Some(path) => {
+                ical::export_to_file(&path, events)?;
+                println!("Wyeksportowano {} wydarzeń do {}.", events.len(), path.display());
+            }
+            None => print!("{}", ical::export_events(events)),
+        },
+        Command::Import { source } => {
+            let summary = ical::import_from_file(&source, events)?;
+            storage.save(events)?;
+            println!("{}", summary);
+        }
+        Command::Filter { name: None, .. } => {
+            if config.filters.is_empty() {
+                println!("Brak zapisanych filtrów.");
+            }
+            for (name, query) in &config.filters {
+                println!("{:<16}  {}", name, query);
+            }
+        }
+        Command::Filter { name: Some(name), delete: true, .. } => {
+            if config.filters.remove(&name).is_none() {
+                return Err(format!("Nie ma zapisanego filtra „{}”.", name).into());
+            }
+            config.save()?;
+            println!("Usunięto filtr {}.", name);
+        }
+        Command::Filter { name: Some(name), query: Some(query), .. } => {
+            let query = FilterQuery::parse(&query)?;
+            config.filters.insert(name.clone(), query.text().to_string());
+            config.save()?;
+            println!("Zapisano filtr {}: {}", name, query.text());
+        }
+        Command::Filter { name: Some(name), query: None, .. } => {
+            let query = config
+                .filters
+                .get(&name)
+                .ok_or_else(|| format!("Nie ma zapisanego filtra „{}”.", name))?;
+            println!("{}", query);
+        }
+        Command::Restore { number: None } => {
+            let backups = storage.backups();
+            if backups.is_empty() {
+                println!("Brak kopii zapasowych pliku {}.", storage.path.display());
+            }
+            for backup in backups {
+                println!(
+                    "{:>2}  {}  {}  {}",
+                    backup.number,
+                    backup
+                        .modified
+                        .map(|m| m.format(DATE_TIME_FORMAT).to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                    backup
+                        .event_count
+                        .map(|count| format!("{} wydarzeń", count))
+                        .unwrap_or_else(|| "plik nieczytelny".to_string()),
+                    backup.path.display()
+                );
+            }
+        }
+        Command::Restore { number: Some(number) } => {
+            *events = storage.restore(number)?;
+            println!("Przywrócono kopię nr {} ({} wydarzeń).", number, events.len());
+        }
+    }
+
+    Ok(())
+}
+
+fn find_event(events: &[Event], id: &str) -> Result<usize, String> {
+    let needle = id.trim().to_lowercase().replace('-', "");
+    let matches: Vec<usize> = events
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| !needle.is_empty() && e.id.simple().to_string().starts_with(&needle))
+        .map(|(index, _)| index)
+        .collect();
+
+    match matches.as_slice() {
+        [index] => Ok(*index),
+        [] => Err(format!("Nie znaleziono wydarzenia o identyfikatorze {}.", id)),
+        _ => Err(format!("Identyfikator {} pasuje do wielu wydarzeń, podaj dłuższy.", id)),
+    }
+}
+
+fn format_with_original(time: DateTime<Local>, zone: Option<Tz>) -> String {
+    match format_original_time(time, zone) {
+        Some(original) => format!("{} ({})", time.format(DATE_TIME_FORMAT), original),
+        None => time.format(DATE_TIME_FORMAT).to_string(),
+    }
+}
+
+fn warn_about_conflicts(conflicts: &[Event]) {
+    if conflicts.is_empty() {
+        return;
+    }
+    eprintln!("Uwaga: wydarzenie nakłada się na:");
+    for conflict in conflicts.iter().take(5) {
+        eprintln!("  {}", format_occurrence(conflict, false));
+    }
+    if conflicts.len() > 5 {
+        eprintln!("  ... i {} innych", conflicts.len() - 5);
+    }
+}
+
+fn format_occurrence(event: &Event, conflicting: bool) -> String {
+    let mut line = format!("{}  {}  {}", event.short_id(), format_event_time(event), event.title);
+    if let Some(ref color) = event.color {
+        line.push_str(&format!(" [{}]", color));
+    }
+    if let Some(ref recurrence) = event.recurrence {
+        line.push_str(&format!(" ({})", format_recurrence(recurrence)));
+    }
+    if conflicting {
+        line.push_str(" [konflikt]");
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn scope_all_edit_through_occurrence_keeps_series_start() {
+        let start = Local.with_ymd_and_hms(2026, 10, 5, 9, 0, 0).unwrap();
+        let excluded = Local.with_ymd_and_hms(2026, 10, 19, 9, 0, 0).unwrap();
+        let mut series = Event {
+            recurrence: parse_recurrence("weekly").unwrap(),
+            count: Some(6),
+            ..Event::new("Spotkanie".to_string(), start, start + Duration::hours(1))
+        };
+        series.exclude_occurrence(excluded);
+        let mut events = vec![series.clone()];
+
+        let args = OccurrenceArgs {
+            occurrence: Some(Local.with_ymd_and_hms(2026, 10, 26, 9, 0, 0).unwrap()),
+            scope: Some(EditScope::All),
+        };
+        let (mut edited, scope) = args.resolve(&series).unwrap();
+        let occurrence_start = edited.occurrence_start();
+        edited.title = "Renamed".to_string();
+        edit_occurrence(&mut events, series.id, occurrence_start, scope, edited);
+
+        assert_eq!(events[0].title, "Renamed");
+        assert_eq!(events[0].start, start);
+        assert_eq!(events[0].exceptions, vec![excluded]);
+    }
+}
@@ -0,0 +1,84 @@
+use std::{collections::BTreeMap, fs::{self, File}, io::{self, BufReader}, path::PathBuf};
+use chrono::{Local, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+use crate::model::query::FilterQuery;
+use crate::storage::{StorageError, APP_DIR};
+
+const FILE_NAME: &str = "config.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub backup_count: usize,
+    pub working_hours: WorkingHours,
+    pub filters: BTreeMap<String, String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            backup_count: 5,
+            working_hours: WorkingHours::default(),
+            filters: BTreeMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkingHours {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+    pub days: Vec<Weekday>,
+}
+
+impl Default for WorkingHours {
+    fn default() -> Self {
+        Self {
+            start: NaiveTime::from_hms_opt(9, 0, 0).expect("poprawna godzina"),
+            end: NaiveTime::from_hms_opt(17, 0, 0).expect("poprawna godzina"),
+            days: vec![Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri],
+        }
+    }
+}
+
+impl Config {
+    pub fn path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join(APP_DIR).join(FILE_NAME))
+    }
+
+    pub fn load() -> Result<Self, StorageError> {
+        let path = match Self::path() {
+            Some(path) if path.exists() => path,
+            _ => return Ok(Self::default()),
+        };
+
+        let file = File::open(&path).map_err(|source| StorageError::Read { path: path.clone(), source })?;
+        serde_json::from_reader(BufReader::new(file)).map_err(|source| StorageError::Corrupt { path, source })
+    }
+
+    pub fn save(&self) -> Result<(), StorageError> {
+        let path = Self::path().unwrap_or_else(|| PathBuf::from(FILE_NAME));
+        let write_error = |source| StorageError::Write { path: path.clone(), source };
+
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(write_error)?;
+        }
+        if path.exists() && Self::load().is_err() {
+            let backup = path.with_extension(format!("json.corrupt-{}", Local::now().format("%Y%m%d-%H%M%S")));
+            fs::rename(&path, &backup).map_err(|source| StorageError::Backup { path: path.clone(), source })?;
+        }
+        let contents = serde_json::to_string_pretty(self).map_err(|err| write_error(io::Error::other(err)))?;
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, contents).map_err(write_error)?;
+        fs::rename(&temp_path, &path).map_err(write_error)
+    }
+
+    pub fn named_filter(&self, name: &str) -> Result<FilterQuery, String> {
+        let text = self
+            .filters
+            .get(name)
+            .ok_or_else(|| format!("Nie ma zapisanego filtra „{}”.", name))?;
+        FilterQuery::parse(text)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,784 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::{OffsetComponents, OffsetName, Tz};
+use uuid::Uuid;
+use crate::model::event::Event;
+use crate::model::recurrence::{ByDay, Frequency, Recurrence};
+use crate::utils::time::{local_time_zone, resolve_in, wall_clock};
+
+const PRODID: &str = "-//calendar-planner//PL";
+const MAX_LINE_OCTETS: usize = 75;
+
+pub struct ImportSummary {
+    pub added: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub unsupported: usize,
+}
+
+impl fmt::Display for ImportSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Zaimportowano {} wydarzeń, zaktualizowano {}, pominięto {} duplikatów",
+            self.added, self.updated, self.skipped
+        )?;
+        if self.unsupported > 0 {
+            write!(f, " i {} nieobsługiwanych lub niepoprawnych wydarzeń", self.unsupported)?;
+        }
+        write!(f, ".")
+    }
+}
+
+pub struct ParsedCalendar {
+    pub events: Vec<Event>,
+    pub unsupported: usize,
+}
+
+pub fn export_to_file(path: &Path, events: &[Event]) -> Result<(), Box<dyn std::error::Error>> {
+    fs::write(path, export_events(events))?;
+    Ok(())
+}
+
+pub fn import_from_file(path: &Path, events: &mut Vec<Event>) -> Result<ImportSummary, Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(path)?;
+    let parsed = parse_events(&content)?;
+    let mut summary = merge_events(events, parsed.events);
+    summary.unsupported = parsed.unsupported;
+    Ok(summary)
+}
+
+pub fn export_events(events: &[Event]) -> String {
+    let stamp = format_utc(Utc::now());
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+    ];
+
+    let mut zones: Vec<Tz> = events.iter().filter(|e| !e.all_day).filter_map(|e| e.time_zone).collect();
+    zones.sort_by_key(|zone| zone.name());
+    zones.dedup();
+    for zone in zones {
+        lines.extend(time_zone_lines(zone, Local::now().year()));
+    }
+
+    for event in events {
+        let uid = event.uid.clone().unwrap_or_else(|| event.id.to_string());
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}", uid));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(date_time_line("DTSTART", event.start, event));
+        lines.push(date_time_line("DTEND", event.end, event));
+        lines.push(format!("SUMMARY:{}", escape_text(&event.title)));
+        lines.extend(detail_lines(event.description.as_deref(), event.location.as_deref(), event.url.as_deref()));
+        if let Some(ref color) = event.color {
+            lines.push(format!("COLOR:{}", escape_text(color)));
+        }
+        if let Some(ref recurrence) = event.recurrence {
+            let mut rule = recurrence.to_string();
+            if recurrence.frequency == Frequency::Yearly && recurrence.has_by_rules() {
+                rule.push_str(&format!(";BYMONTH={}", wall_clock(event.time_zone, event.start).month()));
+            }
+            match event.until {
+                Some(until) if event.all_day => {
+                    rule.push_str(&format!(";UNTIL={}", wall_clock(event.time_zone, until).format("%Y%m%d")));
+                }
+                Some(until) => rule.push_str(&format!(";UNTIL={}", format_utc(until.with_timezone(&Utc)))),
+                None => {}
+            }
+            if let Some(count) = event.count {
+                rule.push_str(&format!(";COUNT={}", count));
+            }
+            lines.push(format!("RRULE:{}", rule));
+            for exception in &event.exceptions {
+                lines.push(date_time_line("EXDATE", *exception, event));
+            }
+        }
+        lines.push("END:VEVENT".to_string());
+
+        for o in &event.overrides {
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!("UID:{}", uid));
+            lines.push(format!("DTSTAMP:{}", stamp));
+            lines.push(date_time_line("RECURRENCE-ID", o.original_start, event));
+            lines.push(date_time_line("DTSTART", o.start, event));
+            lines.push(date_time_line("DTEND", o.end, event));
+            lines.push(format!("SUMMARY:{}", escape_text(&o.title)));
+            lines.extend(detail_lines(o.description.as_deref(), o.location.as_deref(), o.url.as_deref()));
+            if let Some(ref color) = o.color {
+                lines.push(format!("COLOR:{}", escape_text(color)));
+            }
+            lines.push("END:VEVENT".to_string());
+        }
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+    lines.iter().map(|line| fold_line(line)).collect::<Vec<_>>().join("")
+}
+
+pub fn parse_events(content: &str) -> Result<ParsedCalendar, String> {
+    let mut events: Vec<Event> = Vec::new();
+    let mut unsupported = 0;
+    let mut instances: Vec<(DateTime<Local>, Event)> = Vec::new();
+    let mut current: Option<Vec<Property>> = None;
+    let mut nesting = 0;
+
+    for (number, line) in unfold_lines(content).into_iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let property = parse_property(&line)
+            .ok_or_else(|| format!("Niepoprawna linia {} w pliku iCalendar.", number + 1))?;
+
+        match (property.name.as_str(), property.value.to_uppercase().as_str()) {
+            ("BEGIN", "VEVENT") if current.is_none() => {
+                current = Some(Vec::new());
+                nesting = 0;
+            }
+            ("BEGIN", _) if current.is_some() => nesting += 1,
+            ("END", _) if nesting > 0 => nesting -= 1,
+            ("END", "VEVENT") => {
+                if let Some(properties) = current.take() {
+                    let parsed = event_from_properties(&properties).and_then(|event| {
+                        match properties.iter().find(|p| p.name == "RECURRENCE-ID") {
+                            Some(recurrence_id) => Ok((Some(parse_date_time(recurrence_id)?), event)),
+                            None => Ok((None, event)),
+                        }
+                    });
+                    match parsed {
+                        Ok((Some(original_start), event)) => instances.push((original_start, event)),
+                        Ok((None, event)) => events.push(event),
+                        Err(_) => unsupported += 1,
+                    }
+                }
+            }
+            _ => {
+                if let Some(ref mut properties) = current
+                    && nesting == 0
+                {
+                    properties.push(property);
+                }
+            }
+        }
+    }
+
+    for (original_start, instance) in instances {
+        if let Some(series) = events.iter_mut().find(|e| e.id == instance.id) {
+            series.override_occurrence(original_start, &instance);
+        }
+    }
+
+    Ok(ParsedCalendar { events, unsupported })
+}
+
+pub fn merge_events(events: &mut Vec<Event>, imported: Vec<Event>) -> ImportSummary {
+    let mut summary = ImportSummary { added: 0, updated: 0, skipped: 0, unsupported: 0 };
+
+    for event in imported {
+        match events.iter().position(|existing| existing.id == event.id) {
+            Some(index) if events[index] == event => summary.skipped += 1,
+            Some(index) => {
+                events[index] = event;
+                summary.updated += 1;
+            }
+            None if events.iter().any(|existing| is_duplicate(existing, &event)) => summary.skipped += 1,
+            None => {
+                events.push(event);
+                summary.added += 1;
+            }
+        }
+    }
+
+    summary
+}
+
+fn is_duplicate(a: &Event, b: &Event) -> bool {
+    a.title == b.title && a.start == b.start && a.end == b.end && a.recurrence == b.recurrence
+}
+
+struct Property {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl Property {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+fn event_from_properties(properties: &[Property]) -> Result<Event, String> {
+    let find = |name: &str| properties.iter().find(|p| p.name == name);
+
+    let start_property = find("DTSTART").ok_or("Wydarzenie bez pola DTSTART.")?;
+    let start = parse_date_time(start_property)?;
+    let is_date = is_date_value(start_property);
+    let time_zone = property_zone(start_property);
+
+    let end = if let Some(end_property) = find("DTEND") {
+        parse_date_time(end_property)?
+    } else if let Some(duration_property) = find("DURATION") {
+        start
+            .checked_add_signed(parse_ical_duration(&duration_property.value)?)
+            .ok_or_else(|| format!("Niepoprawny czas trwania: {}.", duration_property.value))?
+    } else if is_date {
+        start + Duration::days(1)
+    } else {
+        start
+    };
+
+    let (recurrence, until, count) = match find("RRULE") {
+        Some(rule) => rrule_to_recurrence(&rule.value, time_zone, wall_clock(time_zone, start).month())?,
+        None => (None, None, None),
+    };
+
+    let mut exceptions = Vec::new();
+    for property in properties.iter().filter(|p| p.name == "EXDATE") {
+        for value in property.value.split(',') {
+            exceptions.push(parse_date_time_value(
+                &property.name,
+                value,
+                is_date_value(property),
+                property_zone(property),
+            )?);
+        }
+    }
+    exceptions.sort();
+
+    let color = find("COLOR")
+        .or_else(|| find("X-CALENDAR-PLANNER-COLOR"))
+        .map(|p| unescape_text(&p.value))
+        .filter(|c| !c.is_empty());
+
+    let (id, uid) = match find("UID").map(|p| p.value.trim()) {
+        Some(uid) => match Uuid::parse_str(uid) {
+            Ok(id) => (id, None),
+            Err(_) => (Uuid::new_v5(&Uuid::NAMESPACE_URL, uid.as_bytes()), Some(uid.to_string())),
+        },
+        None => (Uuid::new_v4(), None),
+    };
+    let title = find("SUMMARY").map(|p| unescape_text(&p.value)).unwrap_or_default();
+    let text = |name: &str| find(name).map(|p| unescape_text(&p.value)).filter(|t| !t.trim().is_empty());
+
+    Ok(Event {
+        id,
+        uid,
+        color,
+        description: text("DESCRIPTION"),
+        location: text("LOCATION"),
+        url: find("URL").map(|p| p.value.trim().to_string()).filter(|u| !u.is_empty()),
+        all_day: is_date,
+        time_zone: time_zone.or_else(local_time_zone),
+        recurrence,
+        until,
+        count,
+        exceptions,
+        ..Event::new(title, start, end)
+    })
+}
+
+type RuleParts = (Option<Recurrence>, Option<DateTime<Local>>, Option<u32>);
+
+fn rrule_to_recurrence(rule: &str, zone: Option<Tz>, start_month: u32) -> Result<RuleParts, String> {
+    let mut parts = Vec::new();
+    let mut until = None;
+    let mut count = None;
+    let mut by_month = None;
+
+    for part in rule.split(';') {
+        match part.split_once('=') {
+            Some(("UNTIL", value)) if value.len() == 8 => {
+                let error = || format!("Niepoprawna data w polu UNTIL: {}.", value);
+                let date = NaiveDate::parse_from_str(value, "%Y%m%d").map_err(|_| error())?;
+                until = Some(resolve_in(zone, date.and_hms_opt(23, 59, 59).ok_or_else(error)?));
+            }
+            Some(("UNTIL", value)) => until = Some(parse_date_time_value("UNTIL", value, false, zone)?),
+            Some(("COUNT", value)) => {
+                count = Some(value.parse().map_err(|_| format!("Niepoprawny COUNT w regule {}.", rule))?);
+            }
+            Some(("BYMONTH", value)) => by_month = Some(value),
+            Some(("WKST", _)) => {}
+            _ => parts.push(part),
+        }
+    }
+
+    let recurrence = parts.join(";").parse::<Recurrence>()?;
+    if let Some(months) = by_month
+        && (recurrence.frequency != Frequency::Yearly || months.parse() != Ok(start_month))
+    {
+        return Err(format!("Nieobsługiwana reguła BYMONTH={} w regule {}.", months, rule));
+    }
+    Ok((Some(recurrence), until, count))
+}
+
+fn is_date_value(property: &Property) -> bool {
+    property.param("VALUE").is_some_and(|v| v.eq_ignore_ascii_case("DATE")) || property.value.len() == 8
+}
+
+fn property_zone(property: &Property) -> Option<Tz> {
+    property
+        .param("TZID")
+        .and_then(|id| id.trim_matches('"').trim_start_matches('/').parse().ok())
+}
+
+fn parse_date_time(property: &Property) -> Result<DateTime<Local>, String> {
+    parse_date_time_value(&property.name, &property.value, is_date_value(property), property_zone(property))
+}
+
+fn parse_date_time_value(name: &str, value: &str, is_date: bool, zone: Option<Tz>) -> Result<DateTime<Local>, String> {
+    let value = value.trim();
+    let error = || format!("Niepoprawna data w polu {}: {}.", name, value);
+
+    if is_date {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").map_err(|_| error())?;
+        return Ok(resolve_in(zone, date.and_hms_opt(0, 0, 0).ok_or_else(error)?));
+    }
+
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").map_err(|_| error())?;
+        return Ok(Utc.from_utc_datetime(&naive).with_timezone(&Local));
+    }
+
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").map_err(|_| error())?;
+    Ok(resolve_in(zone, naive))
+}
+
+fn parse_ical_duration(value: &str) -> Result<Duration, String> {
+    let error = || format!("Niepoprawny czas trwania: {}.", value);
+    let (negative, rest) = match value.trim().strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value.trim().trim_start_matches('+')),
+    };
+    let rest = rest.strip_prefix('P').ok_or_else(error)?;
+
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    let mut in_time = false;
+
+    for c in rest.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => in_time = true,
+            _ => {
+                let amount: i64 = number.parse().map_err(|_| error())?;
+                number.clear();
+                let part = match (c, in_time) {
+                    ('W', false) => Duration::try_weeks(amount),
+                    ('D', false) => Duration::try_days(amount),
+                    ('H', true) => Duration::try_hours(amount),
+                    ('M', true) => Duration::try_minutes(amount),
+                    ('S', true) => Duration::try_seconds(amount),
+                    _ => return Err(error()),
+                };
+                total = part.and_then(|part| total.checked_add(&part)).ok_or_else(error)?;
+            }
+        }
+    }
+
+    if !number.is_empty() {
+        return Err(error());
+    }
+
+    Ok(if negative { -total } else { total })
+}
+
+fn date_time_line(name: &str, time: DateTime<Local>, event: &Event) -> String {
+    if event.all_day {
+        return format!("{};VALUE=DATE:{}", name, wall_clock(event.time_zone, time).format("%Y%m%d"));
+    }
+
+    match event.time_zone {
+        Some(zone) => format!("{};TZID={}:{}", name, zone.name(), time.with_timezone(&zone).format("%Y%m%dT%H%M%S")),
+        None => format!("{}:{}", name, format_utc(time.with_timezone(&Utc))),
+    }
+}
+
+fn time_zone_lines(zone: Tz, year: i32) -> Vec<String> {
+    let offset_at = |time: DateTime<Utc>| zone.offset_from_utc_datetime(&time.naive_utc());
+    let year_start = Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap();
+    let year_end = Utc.with_ymd_and_hms(year + 1, 1, 1, 0, 0, 0).unwrap();
+
+    let mut transitions = Vec::new();
+    let mut day = year_start;
+    while day < year_end {
+        let next_day = day + Duration::days(1);
+        if offset_at(day).fix() != offset_at(next_day).fix() {
+            let (mut before, mut after) = (day, next_day);
+            while after - before > Duration::seconds(1) {
+                let middle = before + (after - before) / 2;
+                if offset_at(middle).fix() == offset_at(day).fix() {
+                    before = middle;
+                } else {
+                    after = middle;
+                }
+            }
+            transitions.push(after);
+        }
+        day = next_day;
+    }
+
+    let mut lines = vec!["BEGIN:VTIMEZONE".to_string(), format!("TZID:{}", zone.name())];
+    if transitions.is_empty() {
+        let offset = offset_at(year_start);
+        lines.extend([
+            "BEGIN:STANDARD".to_string(),
+            "DTSTART:19700101T000000".to_string(),
+            format!("TZOFFSETFROM:{}", format_offset(offset.fix())),
+            format!("TZOFFSETTO:{}", format_offset(offset.fix())),
+        ]);
+        lines.extend(offset.abbreviation().map(|name| format!("TZNAME:{}", name)));
+        lines.push("END:STANDARD".to_string());
+    }
+    for at in transitions {
+        let before = offset_at(at - Duration::seconds(1)).fix();
+        let after = offset_at(at);
+        let local = at.with_timezone(&before).naive_local();
+        let month = local.month();
+        let last = (local.date() + Duration::days(7)).month() != month;
+        let by_day = ByDay {
+            nth: Some(if last { -1 } else { ((local.day() - 1) / 7 + 1) as i32 }),
+            weekday: local.weekday(),
+        };
+        let matching: Vec<NaiveDate> = NaiveDate::from_ymd_opt(1970, month, 1)
+            .unwrap()
+            .iter_days()
+            .take_while(|date| date.month() == month)
+            .filter(|date| date.weekday() == by_day.weekday)
+            .collect();
+        let first = if last { matching[matching.len() - 1] } else { matching[(local.day() as usize - 1) / 7] };
+        let kind = if after.dst_offset().is_zero() { "STANDARD" } else { "DAYLIGHT" };
+
+        lines.push(format!("BEGIN:{}", kind));
+        lines.push(format!("DTSTART:{}", first.and_time(local.time()).format("%Y%m%dT%H%M%S")));
+        lines.push(format!("TZOFFSETFROM:{}", format_offset(before)));
+        lines.push(format!("TZOFFSETTO:{}", format_offset(after.fix())));
+        lines.push(format!("RRULE:FREQ=YEARLY;BYMONTH={};BYDAY={}", month, by_day));
+        lines.extend(after.abbreviation().map(|name| format!("TZNAME:{}", name)));
+        lines.push(format!("END:{}", kind));
+    }
+    lines.push("END:VTIMEZONE".to_string());
+    lines
+}
+
+fn format_offset(offset: FixedOffset) -> String {
+    let seconds = offset.local_minus_utc();
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let minutes = seconds.abs() / 60;
+    format!("{}{:02}{:02}", sign, minutes / 60, minutes % 60)
+}
+
+fn detail_lines(description: Option<&str>, location: Option<&str>, url: Option<&str>) -> Vec<String> {
+    let mut lines = Vec::new();
+    if let Some(description) = description {
+        lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+    }
+    if let Some(location) = location {
+        lines.push(format!("LOCATION:{}", escape_text(location)));
+    }
+    if let Some(url) = url {
+        lines.push(format!("URL:{}", url));
+    }
+    lines
+}
+
+fn format_utc(date: DateTime<Utc>) -> String {
+    date.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+fn unescape_text(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') | Some('N') => result.push('\n'),
+                Some(other) => result.push(other),
+                None => result.push('\\'),
+            }
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut octets = 0;
+
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            octets = 1;
+        }
+        folded.push(c);
+        octets += c.len_utf8();
+    }
+
+    folded.push_str("\r\n");
+    folded
+}
+
+fn unfold_lines(content: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for raw in content.lines() {
+        let line = raw.trim_end_matches('\r');
+        match (line.strip_prefix(' ').or_else(|| line.strip_prefix('\t')), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ => lines.push(line.to_string()),
+        }
+    }
+
+    lines
+}
+
+fn parse_property(line: &str) -> Option<Property> {
+    let mut in_quotes = false;
+    let colon = line.char_indices().find(|&(_, c)| {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        }
+        c == ':' && !in_quotes
+    })?.0;
+
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let mut parts = head.split(';');
+    let name = parts.next()?.trim().to_uppercase();
+    let params = parts
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| (k.trim().to_uppercase(), v.trim_matches('"').to_string()))
+        .collect();
+
+    Some(Property { name, params, value: value.to_string() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::model::recurrence::Recurrence;
+
+    fn calendar(body: &str) -> String {
+        format!("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n{}END:VCALENDAR\r\n", body)
+    }
+
+    #[test]
+    fn nested_alarm_properties_do_not_leak_into_event() {
+        let content = calendar(
+            "BEGIN:VEVENT\r\n\
+             UID:abc123@google.com\r\n\
+             DTSTART:20261019T080000Z\r\n\
+             SUMMARY:Spotkanie\r\n\
+             BEGIN:VALARM\r\n\
+             ACTION:DISPLAY\r\n\
+             DESCRIPTION:This is an event reminder\r\n\
+             DURATION:PT15M\r\n\
+             END:VALARM\r\n\
+             END:VEVENT\r\n",
+        );
+
+        let events = parse_events(&content).unwrap().events;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].title, "Spotkanie");
+        assert_eq!(events[0].description, None);
+        assert_eq!(events[0].end, events[0].start);
+    }
+
+    #[test]
+    fn foreign_uid_is_kept_for_export() {
+        let content = calendar(
+            "BEGIN:VEVENT\r\n\
+             UID:abc123@google.com\r\n\
+             DTSTART:20261019T080000Z\r\n\
+             SUMMARY:Spotkanie\r\n\
+             END:VEVENT\r\n",
+        );
+
+        let imported = parse_events(&content).unwrap().events;
+        assert_eq!(imported[0].id, Uuid::new_v5(&Uuid::NAMESPACE_URL, b"abc123@google.com"));
+        assert_eq!(imported[0].uid.as_deref(), Some("abc123@google.com"));
+        let exported = export_events(&imported);
+        assert!(exported.contains("UID:abc123@google.com\r\n"));
+        assert!(!exported.contains(&imported[0].id.to_string()));
+
+        let mut events = imported.clone();
+        let summary = merge_events(&mut events, parse_events(&exported).unwrap().events);
+        assert_eq!((summary.added, summary.updated, summary.skipped), (0, 0, 1));
+
+        let native = Event::new("Lokalne".to_string(), Local::now(), Local::now());
+        assert!(export_events(std::slice::from_ref(&native)).contains(&format!("UID:{}\r\n", native.id)));
+        assert_eq!(parse_events(&export_events(std::slice::from_ref(&native))).unwrap().events[0].uid, None);
+    }
+
+    #[test]
+    fn exported_zones_get_a_vtimezone() {
+        let lines = time_zone_lines(chrono_tz::Europe::Warsaw, 2026);
+        let expected = [
+            "BEGIN:DAYLIGHT",
+            "DTSTART:19700329T020000",
+            "TZOFFSETFROM:+0100",
+            "TZOFFSETTO:+0200",
+            "RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU",
+            "TZNAME:CEST",
+            "END:DAYLIGHT",
+            "BEGIN:STANDARD",
+            "DTSTART:19701025T030000",
+            "TZOFFSETFROM:+0200",
+            "TZOFFSETTO:+0100",
+            "RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU",
+            "TZNAME:CET",
+            "END:STANDARD",
+        ];
+        assert_eq!(lines[..2], ["BEGIN:VTIMEZONE", "TZID:Europe/Warsaw"]);
+        assert_eq!(lines[2..lines.len() - 1], expected);
+
+        let lines = time_zone_lines(chrono_tz::Asia::Kolkata, 2026);
+        assert!(lines.contains(&"TZOFFSETTO:+0530".to_string()));
+        assert!(!lines.iter().any(|line| line.starts_with("RRULE")));
+
+        let mut event = Event::new("Spotkanie".to_string(), Local::now(), Local::now());
+        event.time_zone = Some(chrono_tz::America::New_York);
+        let exported = export_events(&[event.clone(), event]);
+        assert_eq!(exported.matches("TZID:America/New_York\r\n").count(), 1);
+    }
+
+    #[test]
+    fn oversized_durations_are_errors() {
+        for duration in ["P99999999999999W", "P999999999D", "PT99999999999999999H"] {
+            let content = calendar(&format!(
+                "BEGIN:VEVENT\r\nDTSTART:20261019T080000Z\r\nDURATION:{}\r\nSUMMARY:X\r\nEND:VEVENT\r\n",
+                duration
+            ));
+            let parsed = parse_events(&content).unwrap();
+            assert!(parsed.events.is_empty(), "{}", duration);
+            assert_eq!(parsed.unsupported, 1, "{}", duration);
+        }
+        assert_eq!(parse_ical_duration("P1W2DT3H"), Ok(Duration::days(9) + Duration::hours(3)));
+        assert!(parse_ical_duration("P99999999999999W").is_err());
+        assert!(parse_ical_duration("P999999999D").is_ok());
+    }
+
+    #[test]
+    fn unsupported_rules_skip_only_their_event() {
+        let content = calendar(
+            "BEGIN:VEVENT\r\n\
+             DTSTART:20261019T080000Z\r\n\
+             RRULE:FREQ=HOURLY\r\n\
+             SUMMARY:Co godzinę\r\n\
+             END:VEVENT\r\n\
+             BEGIN:VEVENT\r\n\
+             DTSTART;TZID=Europe/Warsaw:20260329T020000\r\n\
+             RRULE:FREQ=YEARLY;BYMONTH=3,10;BYDAY=-1SU\r\n\
+             SUMMARY:Zmiana czasu\r\n\
+             END:VEVENT\r\n\
+             BEGIN:VEVENT\r\n\
+             DTSTART;TZID=Europe/Warsaw:20260329T100000\r\n\
+             RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r\n\
+             SUMMARY:Ostatnia niedziela marca\r\n\
+             END:VEVENT\r\n\
+             BEGIN:VEVENT\r\n\
+             DTSTART:20261020T080000Z\r\n\
+             SUMMARY:Spotkanie\r\n\
+             END:VEVENT\r\n",
+        );
+
+        let parsed = parse_events(&content).unwrap();
+        let titles: Vec<&str> = parsed.events.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, ["Ostatnia niedziela marca", "Spotkanie"]);
+        assert_eq!(parsed.unsupported, 2);
+
+        let mut events = Vec::new();
+        let mut summary = merge_events(&mut events, parsed.events);
+        summary.unsupported = parsed.unsupported;
+        assert_eq!(
+            summary.to_string(),
+            "Zaimportowano 2 wydarzeń, zaktualizowano 0, pominięto 0 duplikatów i 2 nieobsługiwanych lub niepoprawnych wydarzeń."
+        );
+    }
+
+    #[test]
+    fn all_day_series_until_is_a_date() {
+        let first = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+        let mut event = Event::new_all_day("Dyżur".to_string(), None, first, 1);
+        event.recurrence = Some("FREQ=WEEKLY".parse().unwrap());
+        event.until = Some(resolve_in(None, NaiveDate::from_ymd_opt(2026, 11, 2).unwrap().and_hms_opt(23, 59, 59).unwrap()));
+
+        let exported = export_events(std::slice::from_ref(&event));
+        assert!(exported.contains("DTSTART;VALUE=DATE:20261019\r\n"));
+        assert!(exported.contains("RRULE:FREQ=WEEKLY;UNTIL=20261102\r\n"));
+
+        let imported = parse_events(&exported).unwrap().events;
+        assert_eq!(imported[0].until, event.until);
+        let to = resolve_in(None, NaiveDate::from_ymd_opt(2026, 12, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        assert_eq!(imported[0].occurrences_between(event.start, to).len(), 3);
+    }
+
+    #[test]
+    fn folded_lines_are_joined() {
+        let content = calendar(
+            "BEGIN:VEVENT\r\n\
+             DTSTART:20261019T080000Z\r\n\
+             DTEND:20261019T090000Z\r\n\
+             SUMMARY:Bardzo długi tytuł, który\r\n  został złamany\r\n\
+             END:VEVENT\r\n",
+        );
+
+        let events = parse_events(&content).unwrap().events;
+        assert_eq!(events[0].title, "Bardzo długi tytuł, który został złamany");
+    }
+
+    #[test]
+    fn escaped_text_is_unescaped() {
+        let content = calendar(
+            "BEGIN:VEVENT\r\n\
+             DTSTART:20261019T080000Z\r\n\
+             SUMMARY:Budżet\\; koszty\\, plan\r\n\
+             DESCRIPTION:Linia 1\\nLinia 2\\\\koniec\r\n\
+             END:VEVENT\r\n",
+        );
+
+        let events = parse_events(&content).unwrap().events;
+        assert_eq!(events[0].title, "Budżet; koszty, plan");
+        assert_eq!(events[0].description.as_deref(), Some("Linia 1\nLinia 2\\koniec"));
+    }
+
+    #[test]
+    fn export_then_import_round_trips() {
+        let start = Local.with_ymd_and_hms(2026, 10, 19, 9, 0, 0).unwrap();
+        let mut event = Event {
+            color: Some("red".to_string()),
+            description: Some("Agenda:\n1. Budżet; koszty, plan".to_string()),
+            location: Some("Sala 3, piętro 2".to_string()),
+            url: Some("https://meet.example.com/abc".to_string()),
+            recurrence: Some("FREQ=WEEKLY".parse::<Recurrence>().unwrap()),
+            count: Some(5),
+            ..Event::new("Przegląd projektu z bardzo długim tytułem, który trzeba złamać".to_string(), start, start + Duration::hours(1))
+        };
+        event.exclude_occurrence(start + Duration::weeks(1));
+        let moved = start + Duration::weeks(2) + Duration::hours(2);
+        event.override_occurrence(
+            start + Duration::weeks(2),
+            &Event { location: Some("Online".to_string()), ..Event::new("Przeniesione".to_string(), moved, moved + Duration::hours(1)) },
+        );
+
+        let imported = parse_events(&export_events(std::slice::from_ref(&event))).unwrap().events;
+        assert_eq!(imported, vec![event]);
+    }
+}
@@ -1,14 +1,50 @@
-mod app;
+mod cli;
+mod config;
+mod ical;
 mod model;
 mod ui;
 mod storage;
 mod utils;
 
+use clap::Parser;
+use cli::{Cli, Command};
+use config::Config;
+use storage::{resolve_path, Storage};
 use ui::run_ui;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mut events = crate::storage::load_events();
-    run_ui(&mut events)?;
-    crate::storage::save_events(&events)?;
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("Błąd: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+    let mut config = Config::load().unwrap_or_else(|err| {
+        eprintln!("Uwaga: {}. Używam ustawień domyślnych.", err);
+        Config::default()
+    });
+    let storage = Storage::new(resolve_path(cli.file), config.backup_count);
+    let loaded = storage.load();
+
+    match cli.command {
+        None | Some(Command::Tui) => {
+            let (mut events, load_error) = match loaded {
+                Ok(events) => (events, None),
+                Err(err) => (Vec::new(), Some(err)),
+            };
+            run_ui(&mut events, &storage, &config, load_error)?;
+        }
+        Some(command @ Command::Restore { .. }) => {
+            let mut events = loaded.unwrap_or_default();
+            cli::run(command, &mut events, &storage, &mut config)?;
+        }
+        Some(command) => {
+            let mut events = loaded?;
+            cli::run(command, &mut events, &storage, &mut config)?;
+        }
+    }
+
     Ok(())
 }
\ No newline at end of file
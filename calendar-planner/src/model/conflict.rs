@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+use chrono::{DateTime, Duration, Local};
+use uuid::Uuid;
+use crate::model::event::Event;
+
+pub type OccurrenceKey = (Uuid, DateTime<Local>);
+
+pub fn occurrence_key(event: &Event) -> OccurrenceKey {
+    (event.id, event.occurrence_start())
+}
+
+pub fn overlaps(a: &Event, b: &Event) -> bool {
+    !a.all_day && !b.all_day && a.start < b.end && b.start < a.end
+}
+
+pub fn find_conflicts(events: &[Event], candidate: &Event, ignored: Option<Uuid>, now: DateTime<Local>) -> Vec<Event> {
+    let (from, to) = if candidate.is_recurring() {
+        let from = candidate.start.max(now);
+        (from, from + Duration::days(365))
+    } else {
+        (candidate.start, candidate.end)
+    };
+    let planned = candidate.occurrences_between(from, to);
+
+    let mut conflicts: Vec<Event> = events
+        .iter()
+        .filter(|e| e.id != candidate.id && Some(e.id) != ignored)
+        .flat_map(|e| e.occurrences_between(from, to))
+        .filter(|existing| planned.iter().any(|p| overlaps(p, existing)))
+        .collect();
+    conflicts.sort_by_key(|e| e.start);
+    conflicts
+}
+
+pub fn overlapping<'a>(occurrences: impl IntoIterator<Item = &'a Event>) -> HashSet<OccurrenceKey> {
+    let mut timed: Vec<&Event> = occurrences.into_iter().filter(|e| !e.all_day).collect();
+    timed.sort_by_key(|e| e.start);
+
+    let mut keys = HashSet::new();
+    for (index, event) in timed.iter().enumerate() {
+        for other in timed[index + 1..].iter().take_while(|other| other.start < event.end) {
+            if overlaps(event, other) {
+                keys.insert(occurrence_key(event));
+                keys.insert(occurrence_key(other));
+            }
+        }
+    }
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(day: u32, hour: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2026, 10, day, hour, 0, 0).unwrap()
+    }
+
+    fn event(title: &str, start: DateTime<Local>, end: DateTime<Local>) -> Event {
+        Event::new(title.to_string(), start, end)
+    }
+
+    #[test]
+    fn touching_intervals_do_not_overlap() {
+        let first = event("A", at(19, 10), at(19, 11));
+        let second = event("B", at(19, 11), at(19, 12));
+        let third = event("C", at(19, 10), at(19, 13));
+        assert!(!overlaps(&first, &second));
+        assert!(overlaps(&first, &third));
+        assert!(overlaps(&second, &third));
+
+        let keys = overlapping([&first, &second]);
+        assert!(keys.is_empty());
+        let keys = overlapping([&first, &second, &third]);
+        assert_eq!(keys.len(), 3);
+    }
+
+    #[test]
+    fn all_day_events_never_conflict() {
+        let meeting = event("Spotkanie", at(19, 10), at(19, 11));
+        let holiday = Event::new_all_day("Urlop".to_string(), None, at(19, 0).date_naive(), 1);
+        assert!(!overlaps(&meeting, &holiday));
+        assert!(overlapping([&meeting, &holiday]).is_empty());
+        assert!(find_conflicts(std::slice::from_ref(&holiday), &meeting, None, at(17, 12)).is_empty());
+    }
+
+    #[test]
+    fn find_conflicts_skips_the_edited_series() {
+        let existing = event("Stałe", at(19, 10), at(19, 11));
+        let candidate = event("Nowe", at(19, 10), at(19, 12));
+        let events = [existing.clone()];
+        assert_eq!(find_conflicts(&events, &candidate, None, at(17, 12)).len(), 1);
+        assert!(find_conflicts(&events, &candidate, Some(existing.id), at(17, 12)).is_empty());
+
+        let same = Event { id: existing.id, ..candidate };
+        assert!(find_conflicts(&events, &same, None, at(17, 12)).is_empty());
+    }
+
+    #[test]
+    fn recurring_candidate_conflicts_with_later_occurrence() {
+        let existing = event("Przegląd", at(26, 9), at(26, 10));
+        let candidate = Event {
+            recurrence: Some("FREQ=WEEKLY".parse().unwrap()),
+            ..event("Cotygodniowe", at(19, 9), at(19, 10))
+        };
+        let conflicts = find_conflicts(&[existing], &candidate, None, at(17, 12));
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].start, at(26, 9));
+    }
+}
@@ -1,66 +1,280 @@
-use chrono::{DateTime, Duration, Local, Datelike, Months};
+use chrono::{DateTime, Days, Duration, Local, NaiveDate, NaiveTime};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::model::recurrence::Recurrence;
+use crate::utils::time::{local_time_zone, resolve_in, start_of_day, wall_clock};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub enum Recurrence {
-    None,
-    Daily,
-    Weekly,
-    Biweekly,
-    Monthly,
-    Yearly
+pub struct OccurrenceOverride {
+    pub original_start: DateTime<Local>,
+    pub title: String,
+    pub start: DateTime<Local>,
+    pub end: DateTime<Local>,
+    pub color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Event {
+    pub id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
     pub title: String,
     pub start: DateTime<Local>,
     pub end: DateTime<Local>,
     pub color: Option<String>,
-    pub recurrence: Recurrence,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub all_day: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<Tz>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<Recurrence>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<DateTime<Local>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exceptions: Vec<DateTime<Local>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overrides: Vec<OccurrenceOverride>,
+    #[serde(skip)]
+    pub recurrence_id: Option<DateTime<Local>>,
 }
 
 impl Event {
-    pub fn generate_occurrences(&self, until: DateTime<Local>) -> Vec<Event> {
-        let mut events = Vec::new();
-
-        if self.recurrence == Recurrence::None {
-            if self.start <= until {
-                events.push(self.clone());
-            }
-            return events;
+    pub fn new(title: String, start: DateTime<Local>, end: DateTime<Local>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            uid: None,
+            title,
+            start,
+            end,
+            color: None,
+            description: None,
+            location: None,
+            url: None,
+            all_day: false,
+            time_zone: local_time_zone(),
+            recurrence: None,
+            until: None,
+            count: None,
+            exceptions: Vec::new(),
+            overrides: Vec::new(),
+            recurrence_id: None,
+        }
+    }
+
+    pub fn new_all_day(title: String, time_zone: Option<Tz>, first_day: NaiveDate, days: u32) -> Self {
+        let start = start_of_day(time_zone, first_day);
+        let mut event = Event { time_zone, ..Event::new(title, start, start) };
+        event.set_days(first_day, days);
+        event
+    }
+
+    pub fn set_days(&mut self, first_day: NaiveDate, days: u32) {
+        let last_day = first_day.checked_add_days(Days::new(days as u64)).unwrap_or(first_day);
+        self.all_day = true;
+        self.start = start_of_day(self.time_zone, first_day);
+        self.end = start_of_day(self.time_zone, last_day);
+    }
+
+    pub fn start_date(&self) -> NaiveDate {
+        if self.all_day {
+            wall_clock(self.time_zone, self.start).date()
+        } else {
+            self.start.date_naive()
+        }
+    }
+
+    pub fn end_date(&self) -> NaiveDate {
+        let end = if self.all_day { wall_clock(self.time_zone, self.end) } else { self.end.naive_local() };
+        if end.time() == NaiveTime::MIN && self.end > self.start {
+            end.date().pred_opt().unwrap_or(end.date())
+        } else {
+            end.date()
         }
+    }
+
+    pub fn day_count(&self) -> u32 {
+        ((self.end_date() - self.start_date()).num_days() + 1).max(1) as u32
+    }
+
+    pub fn is_recurring(&self) -> bool {
+        self.recurrence.is_some()
+    }
+
+    pub fn occurrence_start(&self) -> DateTime<Local> {
+        self.recurrence_id.unwrap_or(self.start)
+    }
+
+    fn instance(&self, original_start: DateTime<Local>) -> Event {
+        let mut event = Event {
+            id: self.id,
+            uid: self.uid.clone(),
+            title: self.title.clone(),
+            start: original_start,
+            end: original_start + (self.end - self.start),
+            color: self.color.clone(),
+            description: self.description.clone(),
+            location: self.location.clone(),
+            url: self.url.clone(),
+            all_day: self.all_day,
+            time_zone: self.time_zone,
+            recurrence: self.recurrence.clone(),
+            until: self.until,
+            count: self.count,
+            exceptions: Vec::new(),
+            overrides: Vec::new(),
+            recurrence_id: Some(original_start),
+        };
+        if self.all_day {
+            event.set_days(wall_clock(self.time_zone, original_start).date(), self.day_count());
+        }
+
+        if let Some(o) = self.overrides.iter().find(|o| o.original_start == original_start) {
+            event.title = o.title.clone();
+            event.start = o.start;
+            event.end = o.end;
+            event.color = o.color.clone();
+            event.description = o.description.clone();
+            event.location = o.location.clone();
+            event.url = o.url.clone();
+        }
+
+        event
+    }
+
+    pub fn find_occurrence(&self, original_start: DateTime<Local>) -> Option<Event> {
+        if !self.is_occurrence(original_start) {
+            None
+        } else if self.is_recurring() {
+            Some(self.instance(original_start))
+        } else {
+            Some(self.clone())
+        }
+    }
+
+    fn is_occurrence(&self, original_start: DateTime<Local>) -> bool {
+        !self.exceptions.contains(&original_start)
+            && self.rule_starts(original_start, original_start).contains(&original_start)
+    }
+
+    pub fn exclude_occurrence(&mut self, original_start: DateTime<Local>) {
+        self.overrides.retain(|o| o.original_start != original_start);
+        if !self.exceptions.contains(&original_start) {
+            self.exceptions.push(original_start);
+            self.exceptions.sort();
+        }
+    }
+
+    pub fn override_occurrence(&mut self, original_start: DateTime<Local>, edited: &Event) {
+        self.overrides.retain(|o| o.original_start != original_start);
+        self.overrides.push(OccurrenceOverride {
+            original_start,
+            title: edited.title.clone(),
+            start: edited.start,
+            end: edited.end,
+            color: edited.color.clone(),
+            description: edited.description.clone(),
+            location: edited.location.clone(),
+            url: edited.url.clone(),
+        });
+        self.overrides.sort_by_key(|o| o.original_start);
+    }
+
+    pub fn end_series_before(&mut self, original_start: DateTime<Local>) {
+        self.until = Some(original_start - Duration::seconds(1));
+        self.exceptions.retain(|e| *e < original_start);
+        self.overrides.retain(|o| o.original_start < original_start);
+    }
+
+    pub fn shift_exceptions(&mut self, delta: Duration) {
+        for exception in &mut self.exceptions {
+            *exception += delta;
+        }
+        for o in &mut self.overrides {
+            o.original_start += delta;
+        }
+    }
+
+    pub fn short_id(&self) -> String {
+        self.id.simple().to_string()[..8].to_string()
+    }
+
+    pub fn occurrences_between(&self, from: DateTime<Local>, to: DateTime<Local>) -> Vec<Event> {
+        let overlaps = |start: DateTime<Local>, end: DateTime<Local>| start < to && (end > from || start >= from);
+        if !self.is_recurring() {
+            return if overlaps(self.start, self.end) { vec![self.clone()] } else { Vec::new() };
+        }
+
+        let earliest = from - (self.end - self.start) - Duration::days(1);
+        let mut starts = self.rule_starts(earliest, to);
+        let moved: Vec<DateTime<Local>> = self
+            .overrides
+            .iter()
+            .filter(|o| overlaps(o.start, o.end))
+            .map(|o| o.original_start)
+            .filter(|start| !starts.contains(start) && self.is_occurrence(*start))
+            .collect();
+        starts.extend(moved);
+
+        let mut occurrences: Vec<Event> = starts
+            .into_iter()
+            .filter(|start| !self.exceptions.contains(start))
+            .map(|start| self.instance(start))
+            .filter(|event| overlaps(event.start, event.end))
+            .collect();
+        occurrences.sort_by_key(|event| event.start);
+        occurrences
+    }
+
+    pub fn occurrences_before(&self, original_start: DateTime<Local>) -> usize {
+        self.rule_starts(self.start, original_start)
+            .into_iter()
+            .filter(|start| *start < original_start)
+            .count()
+    }
+
+    fn rule_starts(&self, from: DateTime<Local>, until: DateTime<Local>) -> Vec<DateTime<Local>> {
+        let until = self.until.map_or(until, |series_end| series_end.min(until));
+        if self.start > until {
+            return Vec::new();
+        }
+        let Some(rule) = &self.recurrence else {
+            return if self.start >= from { vec![self.start] } else { Vec::new() };
+        };
+
+        let first = wall_clock(self.time_zone, self.start);
+        let last = wall_clock(self.time_zone, until);
+        let skip_to = if self.count.is_none() && from > self.start {
+            wall_clock(self.time_zone, from).date()
+        } else {
+            first.date()
+        };
+        let mut starts = vec![self.start];
+        starts.extend(
+            rule.dates_between(first.date(), skip_to, last.date())
+                .into_iter()
+                .map(|date| resolve_in(self.time_zone, date.and_time(first.time())))
+                .filter(|start| *start > self.start && *start <= until),
+        );
 
-        let mut current_start = self.start;
-        let duration = self.end - self.start;
-
-        while current_start <= until {
-            let mut event = self.clone();
-            event.start = current_start;
-            event.end = current_start + duration;
-            events.push(event);
-
-            current_start = match self.recurrence {
-                Recurrence::Daily => current_start + Duration::days(1),
-                Recurrence::Weekly => current_start + Duration::weeks(1),
-                Recurrence::Biweekly => current_start + Duration::weeks(2),
-                Recurrence::Monthly => {
-                    if let Some(new_start) = current_start.checked_add_months(Months::new(1)) {
-                        new_start
-                    } else {
-                        break;
-                    }
-                }
-                Recurrence::Yearly => {
-                    match current_start.with_year(current_start.year() + 1) {
-                        Some(new_date) => new_date,
-                        None => break,
-                    }
-                }
-                Recurrence::None => break,
-            };
+        if let Some(count) = self.count {
+            starts.truncate(count as usize);
         }
-        
-        events
+        starts.retain(|start| *start >= from);
+        starts
     }
 }
\ No newline at end of file
@@ -0,0 +1,148 @@
+use chrono::{DateTime, Datelike, Duration, Local, Months, NaiveDate};
+use clap::ValueEnum;
+use crate::model::event::Event;
+use crate::model::query::FilterQuery;
+use crate::utils::time::start_of_day;
+
+pub const ALL_VIEW_PAGE: Duration = Duration::weeks(8);
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum ViewMode {
+    All,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl ViewMode {
+    pub fn next(self) -> Self {
+        match self {
+            ViewMode::All => ViewMode::Day,
+            ViewMode::Day => ViewMode::Week,
+            ViewMode::Week => ViewMode::Month,
+            ViewMode::Month => ViewMode::Year,
+            ViewMode::Year => ViewMode::All,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ViewMode::All => "Wszystko",
+            ViewMode::Day => "Dzień",
+            ViewMode::Week => "Tydzień",
+            ViewMode::Month => "Miesiąc",
+            ViewMode::Year => "Rok",
+        }
+    }
+
+    pub fn range(self, current_date: NaiveDate) -> Option<(NaiveDate, NaiveDate)> {
+        match self {
+            ViewMode::All => None,
+            ViewMode::Day => Some((current_date, current_date)),
+            ViewMode::Week => Some(week_bounds(current_date)),
+            ViewMode::Month => {
+                let first = current_date.with_day(1)?;
+                Some((first, first.checked_add_months(Months::new(1))?.pred_opt()?))
+            }
+            ViewMode::Year => Some((
+                NaiveDate::from_ymd_opt(current_date.year(), 1, 1)?,
+                NaiveDate::from_ymd_opt(current_date.year(), 12, 31)?,
+            )),
+        }
+    }
+
+    pub fn overlaps(self, current_date: NaiveDate, first: NaiveDate, last: NaiveDate) -> bool {
+        self.range(current_date)
+            .is_none_or(|(range_start, range_end)| first <= range_end && last >= range_start)
+    }
+
+    pub fn describe(self, current_date: NaiveDate) -> String {
+        match self {
+            ViewMode::All => "Widok: Ogólny".to_string(),
+            ViewMode::Day => format!("Widok: Dzień {}", current_date.format("%Y-%m-%d")),
+            ViewMode::Week => {
+                let (start, end) = week_bounds(current_date);
+                format!("Widok: Tydzień {} - {}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"))
+            }
+            ViewMode::Month => format!("Widok: Miesiąc {}", current_date.format("%Y-%m")),
+            ViewMode::Year => format!("Widok: Rok {}", current_date.format("%Y")),
+        }
+    }
+}
+
+pub fn week_bounds(date: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let week_start = date - Duration::days(date.weekday().num_days_from_monday() as i64);
+    (week_start, week_start + Duration::days(6))
+}
+
+#[derive(Clone, PartialEq)]
+pub struct EventFilter {
+    pub view_mode: ViewMode,
+    pub current_date: NaiveDate,
+    pub show_only_upcoming: bool,
+    pub color: Option<String>,
+    pub query: Option<FilterQuery>,
+    pub all_range: (NaiveDate, NaiveDate),
+}
+
+impl EventFilter {
+    pub fn new(current_date: NaiveDate) -> Self {
+        Self {
+            view_mode: ViewMode::All,
+            current_date,
+            show_only_upcoming: false,
+            color: None,
+            query: None,
+            all_range: page_around(current_date),
+        }
+    }
+
+    pub fn matches(&self, event: &Event, now: DateTime<Local>) -> bool {
+        self.view_mode.overlaps(self.current_date, event.start_date(), event.end_date())
+            && (!self.show_only_upcoming || event.start >= now)
+            && (self.color.is_none() || event.color.as_deref() == self.color.as_deref())
+            && self.query.as_ref().is_none_or(|query| query.matches(event, now))
+    }
+
+    pub fn window(&self, now: DateTime<Local>) -> (DateTime<Local>, DateTime<Local>) {
+        let (first, last) = self.view_mode.range(self.current_date).unwrap_or(self.all_range);
+        let from = start_of_day(None, first);
+        let from = if self.show_only_upcoming { from.max(now) } else { from };
+        (from, start_of_day(None, last + Duration::days(1)))
+    }
+
+    pub fn extend_all_range(&mut self, forward: bool) {
+        let (first, last) = self.all_range;
+        self.all_range = if forward { (first, last + ALL_VIEW_PAGE) } else { (first - ALL_VIEW_PAGE, last) };
+    }
+}
+
+pub fn page_around(date: NaiveDate) -> (NaiveDate, NaiveDate) {
+    (date - ALL_VIEW_PAGE, date + ALL_VIEW_PAGE)
+}
+
+pub fn occurrences_between(events: &[Event], from: DateTime<Local>, to: DateTime<Local>) -> Vec<Event> {
+    events
+        .iter()
+        .flat_map(|event| event.occurrences_between(from, to))
+        .collect()
+}
+
+pub fn visible_occurrences<'a>(
+    occurrences: impl IntoIterator<Item = &'a Event>,
+    filter: &EventFilter,
+    now: DateTime<Local>,
+    sort_asc: bool,
+) -> Vec<&'a Event> {
+    let mut visible: Vec<&Event> = occurrences
+        .into_iter()
+        .filter(|e| filter.matches(e, now))
+        .collect();
+
+    visible.sort_by_key(|e| e.start);
+    if !sort_asc {
+        visible.reverse();
+    }
+    visible
+}
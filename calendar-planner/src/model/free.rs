@@ -0,0 +1,98 @@
+use chrono::{DateTime, Datelike, Duration, DurationRound, Local};
+use crate::config::WorkingHours;
+use crate::model::event::Event;
+use crate::model::filter::occurrences_between;
+use crate::utils::time::resolve_in;
+
+pub type Slot = (DateTime<Local>, DateTime<Local>);
+
+pub fn free_slots(
+    events: &[Event],
+    from: DateTime<Local>,
+    to: DateTime<Local>,
+    duration: Duration,
+    hours: &WorkingHours,
+) -> Vec<Slot> {
+    let from = from.duration_round_up(Duration::minutes(15)).unwrap_or(from);
+    let mut busy: Vec<Slot> = occurrences_between(events, from, to)
+        .into_iter()
+        .filter(|e| !e.all_day)
+        .map(|e| (e.start, e.end))
+        .collect();
+    busy.sort();
+
+    let mut slots = Vec::new();
+    for day in from.date_naive().iter_days().take_while(|day| *day <= to.date_naive()) {
+        if !hours.days.contains(&day.weekday()) {
+            continue;
+        }
+
+        let mut cursor = resolve_in(None, day.and_time(hours.start)).max(from);
+        let day_end = resolve_in(None, day.and_time(hours.end)).min(to);
+        for (start, end) in &busy {
+            if *end <= cursor || *start >= day_end {
+                continue;
+            }
+            if *start - cursor >= duration {
+                slots.push((cursor, *start));
+            }
+            cursor = cursor.max(*end);
+        }
+        if day_end - cursor >= duration {
+            slots.push((cursor, day_end));
+        }
+    }
+    slots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Local> {
+        Local.with_ymd_and_hms(2026, 10, day, hour, minute, 0).unwrap()
+    }
+
+    fn event(start: DateTime<Local>, end: DateTime<Local>) -> Event {
+        Event::new("Zajęte".to_string(), start, end)
+    }
+
+    fn monday(events: &[Event], duration: Duration) -> Vec<Slot> {
+        free_slots(events, at(19, 0, 0), at(20, 0, 0), duration, &WorkingHours::default())
+    }
+
+    #[test]
+    fn slot_may_fill_the_whole_working_day() {
+        assert_eq!(monday(&[], Duration::hours(8)), vec![(at(19, 9, 0), at(19, 17, 0))]);
+        assert!(monday(&[], Duration::minutes(8 * 60 + 1)).is_empty());
+    }
+
+    #[test]
+    fn touching_events_leave_no_gap() {
+        let events = [event(at(19, 10, 0), at(19, 11, 0)), event(at(19, 11, 0), at(19, 12, 0))];
+        assert_eq!(
+            monday(&events, Duration::hours(1)),
+            vec![(at(19, 9, 0), at(19, 10, 0)), (at(19, 12, 0), at(19, 17, 0))]
+        );
+        assert_eq!(monday(&events, Duration::minutes(61)), vec![(at(19, 12, 0), at(19, 17, 0))]);
+    }
+
+    #[test]
+    fn busy_time_is_clipped_to_working_hours() {
+        let events = [event(at(19, 7, 0), at(19, 9, 30)), event(at(19, 16, 30), at(19, 19, 0))];
+        assert_eq!(monday(&events, Duration::minutes(30)), vec![(at(19, 9, 30), at(19, 16, 30))]);
+
+        let from = free_slots(&events, at(19, 13, 7), at(19, 15, 0), Duration::minutes(30), &WorkingHours::default());
+        assert_eq!(from, vec![(at(19, 13, 15), at(19, 15, 0))]);
+    }
+
+    #[test]
+    fn all_day_events_and_days_off_are_ignored() {
+        let all_day = Event::new_all_day("Urlop".to_string(), None, at(19, 0, 0).date_naive(), 1);
+        assert_eq!(monday(&[all_day], Duration::hours(1)), vec![(at(19, 9, 0), at(19, 17, 0))]);
+
+        let weekend = free_slots(&[], at(17, 0, 0), at(19, 0, 0), Duration::hours(1), &WorkingHours::default());
+        assert!(weekend.is_empty());
+    }
+}
@@ -1,2 +1,10 @@
+pub mod conflict;
 pub mod event;
-pub use event::{Event, Recurrence};
\ No newline at end of file
+pub mod filter;
+pub mod free;
+pub mod query;
+pub mod recurrence;
+pub mod search;
+pub mod series;
+pub mod store;
+pub use event::Event;
\ No newline at end of file
@@ -0,0 +1,360 @@
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::vec::IntoIter;
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use crate::model::event::Event;
+use crate::model::search::SearchQuery;
+use crate::utils::parse::{parse_date, parse_duration};
+
+const OPERATORS: [&str; 7] = ["<=", ">=", ":", "~", "<", ">", "="];
+
+#[derive(Clone, PartialEq)]
+pub struct FilterQuery {
+    text: String,
+    root: Expr,
+}
+
+#[derive(Clone, PartialEq)]
+enum Expr {
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+    Term(Term),
+}
+
+#[derive(Clone, PartialEq)]
+enum Term {
+    Text(SearchQuery),
+    Title(SearchQuery),
+    Description(SearchQuery),
+    Location(SearchQuery),
+    Color(Option<String>),
+    Recurring(bool),
+    AllDay(bool),
+    Upcoming(bool),
+    After(NaiveDate),
+    Before(NaiveDate),
+    Duration(Comparison, Duration),
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Equal,
+    GreaterOrEqual,
+    Greater,
+}
+
+enum Token {
+    Open,
+    Close,
+    Word(String),
+    Quoted(String),
+}
+
+type Tokens = Peekable<IntoIter<Token>>;
+
+impl FilterQuery {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut tokens = tokenize(text)?.into_iter().peekable();
+        let root = parse_or(&mut tokens)?;
+        if tokens.next().is_some() {
+            return Err("Nieoczekiwany nawias zamykający w zapytaniu.".to_string());
+        }
+        Ok(Self { text: text.trim().to_string(), root })
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn matches(&self, event: &Event, now: DateTime<Local>) -> bool {
+        self.root.matches(event, now)
+    }
+}
+
+impl Expr {
+    fn matches(&self, event: &Event, now: DateTime<Local>) -> bool {
+        match self {
+            Expr::And(terms) => terms.iter().all(|term| term.matches(event, now)),
+            Expr::Or(alternatives) => alternatives.iter().any(|alternative| alternative.matches(event, now)),
+            Expr::Not(inner) => !inner.matches(event, now),
+            Expr::Term(term) => term.matches(event, now),
+        }
+    }
+}
+
+impl Term {
+    fn matches(&self, event: &Event, now: DateTime<Local>) -> bool {
+        match self {
+            Term::Text(query) => query.matches(event),
+            Term::Title(query) => query.matches_text(&event.title),
+            Term::Description(query) => event.description.as_deref().is_some_and(|text| query.matches_text(text)),
+            Term::Location(query) => event.location.as_deref().is_some_and(|text| query.matches_text(text)),
+            Term::Color(color) => event.color.as_ref().map(|c| c.to_lowercase()) == *color,
+            Term::Recurring(expected) => event.is_recurring() == *expected,
+            Term::AllDay(expected) => event.all_day == *expected,
+            Term::Upcoming(expected) => (event.start >= now) == *expected,
+            Term::After(date) => event.start_date() >= *date,
+            Term::Before(date) => event.start_date() < *date,
+            Term::Duration(comparison, length) => comparison.holds((event.end - event.start).cmp(length)),
+        }
+    }
+}
+
+impl Comparison {
+    fn from_operator(operator: &str) -> Self {
+        match operator {
+            "<" => Comparison::Less,
+            "<=" => Comparison::LessOrEqual,
+            ">=" => Comparison::GreaterOrEqual,
+            ">" => Comparison::Greater,
+            _ => Comparison::Equal,
+        }
+    }
+
+    fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Comparison::Less => ordering.is_lt(),
+            Comparison::LessOrEqual => ordering.is_le(),
+            Comparison::Equal => ordering.is_eq(),
+            Comparison::GreaterOrEqual => ordering.is_ge(),
+            Comparison::Greater => ordering.is_gt(),
+        }
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    let mut quoted = false;
+    let mut starts_quoted = false;
+    let flush = |word: &mut String, starts_quoted: &mut bool, tokens: &mut Vec<Token>| {
+        if !word.is_empty() {
+            let word = std::mem::take(word);
+            tokens.push(if *starts_quoted { Token::Quoted(word) } else { Token::Word(word) });
+        }
+        *starts_quoted = false;
+    };
+
+    for c in text.chars() {
+        match c {
+            '"' => {
+                starts_quoted |= !quoted && word.is_empty();
+                quoted = !quoted;
+            }
+            c if quoted => word.push(c),
+            '(' | ')' => {
+                flush(&mut word, &mut starts_quoted, &mut tokens);
+                tokens.push(if c == '(' { Token::Open } else { Token::Close });
+            }
+            c if c.is_whitespace() => flush(&mut word, &mut starts_quoted, &mut tokens),
+            c => word.push(c),
+        }
+    }
+    if quoted {
+        return Err("Niezamknięty cudzysłów w zapytaniu.".to_string());
+    }
+    flush(&mut word, &mut starts_quoted, &mut tokens);
+    Ok(tokens)
+}
+
+fn is_or(token: &Token) -> bool {
+    matches!(token, Token::Word(word) if word.eq_ignore_ascii_case("or") || word.eq_ignore_ascii_case("lub"))
+}
+
+fn parse_or(tokens: &mut Tokens) -> Result<Expr, String> {
+    let mut alternatives = vec![parse_and(tokens)?];
+    while tokens.next_if(is_or).is_some() {
+        alternatives.push(parse_and(tokens)?);
+    }
+    Ok(if alternatives.len() == 1 { alternatives.remove(0) } else { Expr::Or(alternatives) })
+}
+
+fn parse_and(tokens: &mut Tokens) -> Result<Expr, String> {
+    let mut terms = Vec::new();
+    while let Some(token) = tokens.peek() {
+        if matches!(token, Token::Close) || is_or(token) {
+            break;
+        }
+        terms.push(parse_unary(tokens)?);
+    }
+
+    match terms.len() {
+        0 => Err("Brak warunku w zapytaniu.".to_string()),
+        1 => Ok(terms.remove(0)),
+        _ => Ok(Expr::And(terms)),
+    }
+}
+
+fn parse_unary(tokens: &mut Tokens) -> Result<Expr, String> {
+    match tokens.next() {
+        Some(Token::Open) => {
+            let inner = parse_or(tokens)?;
+            match tokens.next() {
+                Some(Token::Close) => Ok(inner),
+                _ => Err("Brak nawiasu zamykającego w zapytaniu.".to_string()),
+            }
+        }
+        Some(Token::Word(word)) if word == "-" => Ok(Expr::Not(Box::new(parse_unary(tokens)?))),
+        Some(Token::Quoted(word)) => phrase(&word).map(|query| Expr::Term(Term::Text(query))),
+        Some(Token::Word(word)) => match word.strip_prefix('-') {
+            Some(negated) => Ok(Expr::Not(Box::new(Expr::Term(parse_term(negated)?)))),
+            None => Ok(Expr::Term(parse_term(&word)?)),
+        },
+        Some(Token::Close) => Err("Nieoczekiwany nawias zamykający w zapytaniu.".to_string()),
+        None => Err("Brak warunku w zapytaniu.".to_string()),
+    }
+}
+
+fn parse_term(word: &str) -> Result<Term, String> {
+    let Some(position) = word.find([':', '~', '<', '>', '=']) else {
+        return phrase(word).map(Term::Text);
+    };
+    let field = word[..position].to_lowercase();
+    let rest = &word[position..];
+    let operator = OPERATORS.iter().find(|operator| rest.starts_with(**operator)).copied().unwrap_or(":");
+    let value = &rest[operator.len()..];
+    let unsupported = || Err(format!("Pole {} nie obsługuje operatora {}.", field, operator));
+
+    match (field.as_str(), operator) {
+        ("title", ":" | "~") => phrase(value).map(Term::Title),
+        ("description", ":" | "~") => phrase(value).map(Term::Description),
+        ("location", ":" | "~") => phrase(value).map(Term::Location),
+        ("color", ":" | "=") => Ok(Term::Color(match value.to_lowercase().as_str() {
+            "none" | "brak" => None,
+            color => Some(color.to_string()),
+        })),
+        ("recurring", ":") => parse_flag(value).map(Term::Recurring),
+        ("allday", ":") => parse_flag(value).map(Term::AllDay),
+        ("upcoming", ":") => parse_flag(value).map(Term::Upcoming),
+        ("after", ":") => parse_date(value).map(Term::After),
+        ("before", ":") => parse_date(value).map(Term::Before),
+        ("duration", "~") => unsupported(),
+        ("duration", _) => parse_length(value).map(|length| Term::Duration(Comparison::from_operator(operator), length)),
+        ("title" | "description" | "location" | "color" | "recurring" | "allday" | "upcoming" | "after" | "before", _) => {
+            unsupported()
+        }
+        _ => Err(format!("Nieznane pole „{}” w zapytaniu.", field)),
+    }
+}
+
+fn phrase(value: &str) -> Result<SearchQuery, String> {
+    SearchQuery::new(value).ok_or_else(|| "Pusta fraza w zapytaniu.".to_string())
+}
+
+fn parse_flag(value: &str) -> Result<bool, String> {
+    match value.to_lowercase().as_str() {
+        "yes" | "true" | "tak" | "1" => Ok(true),
+        "no" | "false" | "nie" | "0" => Ok(false),
+        _ => Err(format!("Niepoprawna wartość „{}” (oczekiwano yes lub no).", value)),
+    }
+}
+
+fn parse_length(value: &str) -> Result<Duration, String> {
+    if let Ok(length) = parse_duration(value) {
+        return Ok(length);
+    }
+
+    let format_error = || format!("Niepoprawny czas trwania „{}” (np. 2h, 30m, 1h30m lub 1:30).", value);
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    for c in value.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let amount: i64 = number.parse().map_err(|_| format_error())?;
+        number.clear();
+        let part = match c {
+            'd' => Duration::try_days(amount),
+            'h' => Duration::try_hours(amount),
+            'm' => Duration::try_minutes(amount),
+            _ => return Err(format_error()),
+        };
+        total = part.and_then(|part| total.checked_add(&part)).ok_or_else(format_error)?;
+    }
+    if value.is_empty() || !number.is_empty() {
+        return Err(format_error());
+    }
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minutes: i64) -> Event {
+        let start = Local.with_ymd_and_hms(2026, 10, 19, hour, 0, 0).unwrap();
+        Event::new("Spotkanie".to_string(), start, start + Duration::minutes(minutes))
+    }
+
+    fn titled(title: &str) -> Event {
+        let mut event = at(10, 60);
+        event.title = title.to_string();
+        event
+    }
+
+    fn matches(query: &str, event: &Event) -> bool {
+        let now = Local.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap();
+        FilterQuery::parse(query).unwrap().matches(event, now)
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        let query = "title:a title:b or title:c";
+        assert!(matches(query, &titled("a b")));
+        assert!(matches(query, &titled("c")));
+        assert!(!matches(query, &titled("a")));
+        assert!(!matches("title:a (title:b or title:c)", &titled("c")));
+        assert!(matches("title:a (title:b lub title:c)", &titled("a c")));
+    }
+
+    #[test]
+    fn negation_applies_to_terms_and_groups() {
+        assert!(matches("-title:a", &titled("b")));
+        assert!(!matches("-title:a", &titled("a")));
+        assert!(!matches("-(title:a or title:b)", &titled("b")));
+        assert!(matches("- (title:a or title:b)", &titled("c")));
+    }
+
+    #[test]
+    fn quoted_phrases_keep_spaces_and_parentheses() {
+        assert!(matches("title:\"plan (q4)\"", &titled("Plan (Q4) zespołu")));
+        assert!(!matches("title:\"plan q4\"", &titled("Plan (Q4) zespołu")));
+        assert!(matches("\"or\"", &titled("Kolor")));
+    }
+
+    #[test]
+    fn quoted_tokens_are_plain_text() {
+        assert!(matches("\"-plan\"", &titled("Re-plan")));
+        assert!(!matches("\"-plan\"", &titled("Plan")));
+        assert!(matches("\"title:a\"", &titled("Pole title:a")));
+        assert!(!matches("\"title:a\"", &titled("a")));
+        assert!(matches("-\"plan\"", &titled("Kolor")));
+    }
+
+    #[test]
+    fn duration_comparisons() {
+        assert!(matches("duration>=1h30m", &at(10, 90)));
+        assert!(!matches("duration>1:30", &at(10, 90)));
+        assert!(matches("duration<2h", &at(10, 90)));
+        assert!(matches("duration=90m", &at(10, 90)));
+    }
+
+    #[test]
+    fn reports_malformed_queries() {
+        let error = |query: &str| FilterQuery::parse(query).err().unwrap();
+        assert_eq!(error("title:\"plan"), "Niezamknięty cudzysłów w zapytaniu.");
+        assert_eq!(error("(title:a"), "Brak nawiasu zamykającego w zapytaniu.");
+        assert_eq!(error("title:a)"), "Nieoczekiwany nawias zamykający w zapytaniu.");
+        assert_eq!(error("title:a or"), "Brak warunku w zapytaniu.");
+        assert_eq!(error("room:a"), "Nieznane pole „room” w zapytaniu.");
+        assert_eq!(error("after<2026-01-01"), "Pole after nie obsługuje operatora <.");
+        assert_eq!(error("recurring:maybe"), "Niepoprawna wartość „maybe” (oczekiwano yes lub no).");
+        assert!(error("duration>99999999999999d").starts_with("Niepoprawny czas trwania"));
+        assert!(error("duration>1x").starts_with("Niepoprawny czas trwania"));
+    }
+}
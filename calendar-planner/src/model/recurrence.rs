@@ -0,0 +1,635 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+use crate::model::filter::week_bounds;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    fn rrule_name(self) -> &'static str {
+        match self {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        }
+    }
+}
+
+impl FromStr for Frequency {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_uppercase().as_str() {
+            "DAILY" => Ok(Frequency::Daily),
+            "WEEKLY" => Ok(Frequency::Weekly),
+            "MONTHLY" => Ok(Frequency::Monthly),
+            "YEARLY" => Ok(Frequency::Yearly),
+            _ => Err(format!("Nieobsługiwana częstotliwość powtarzania: {}.", input)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MissingDay {
+    Skip,
+    Clamp,
+    Roll,
+}
+
+impl MissingDay {
+    fn rrule_name(self) -> &'static str {
+        match self {
+            MissingDay::Skip => "OMIT",
+            MissingDay::Clamp => "BACKWARD",
+            MissingDay::Roll => "FORWARD",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MissingDay::Skip => "skip",
+            MissingDay::Clamp => "clamp",
+            MissingDay::Roll => "roll",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "skip" => Some(MissingDay::Skip),
+            "clamp" => Some(MissingDay::Clamp),
+            "roll" => Some(MissingDay::Roll),
+            _ => None,
+        }
+    }
+
+    fn resolve(self, month_start: NaiveDate, month_length: u32, day: i32) -> Option<NaiveDate> {
+        let resolved = if day > 0 { day } else { month_length as i32 + 1 + day };
+        let day = if resolved < 1 {
+            match self {
+                MissingDay::Skip => return None,
+                MissingDay::Clamp | MissingDay::Roll => 1,
+            }
+        } else if resolved as u32 > month_length {
+            match self {
+                MissingDay::Skip => return None,
+                MissingDay::Clamp => month_length,
+                MissingDay::Roll => month_length + 1,
+            }
+        } else {
+            resolved as u32
+        };
+        month_start.checked_add_days(Days::new(day as u64 - 1))
+    }
+}
+
+impl FromStr for MissingDay {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_uppercase().as_str() {
+            "OMIT" => Ok(MissingDay::Skip),
+            "BACKWARD" => Ok(MissingDay::Clamp),
+            "FORWARD" => Ok(MissingDay::Roll),
+            _ => Err(format!("Nieobsługiwana wartość SKIP: {}.", input)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ByDay {
+    pub nth: Option<i32>,
+    pub weekday: Weekday,
+}
+
+impl ByDay {
+    fn matches(&self, date: NaiveDate, days_in_month: u32) -> bool {
+        if date.weekday() != self.weekday {
+            return false;
+        }
+        match self.nth {
+            Some(nth) if nth > 0 => (date.day() - 1) / 7 + 1 == nth as u32,
+            Some(nth) => (days_in_month - date.day()) / 7 + 1 == nth.unsigned_abs(),
+            None => true,
+        }
+    }
+}
+
+impl fmt::Display for ByDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(nth) = self.nth {
+            write!(f, "{}", nth)?;
+        }
+        let code = match self.weekday {
+            Weekday::Mon => "MO",
+            Weekday::Tue => "TU",
+            Weekday::Wed => "WE",
+            Weekday::Thu => "TH",
+            Weekday::Fri => "FR",
+            Weekday::Sat => "SA",
+            Weekday::Sun => "SU",
+        };
+        write!(f, "{}", code)
+    }
+}
+
+impl FromStr for ByDay {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || format!("Niepoprawny dzień tygodnia: {}.", input);
+        let trimmed = input.trim();
+        if trimmed.len() < 2 || !trimmed.is_char_boundary(trimmed.len() - 2) {
+            return Err(error());
+        }
+
+        let (prefix, code) = trimmed.split_at(trimmed.len() - 2);
+        let weekday = match code.to_uppercase().as_str() {
+            "MO" => Weekday::Mon,
+            "TU" => Weekday::Tue,
+            "WE" => Weekday::Wed,
+            "TH" => Weekday::Thu,
+            "FR" => Weekday::Fri,
+            "SA" => Weekday::Sat,
+            "SU" => Weekday::Sun,
+            _ => return Err(error()),
+        };
+        let nth = match prefix {
+            "" => None,
+            _ => match prefix.parse::<i32>() {
+                Ok(nth) if nth != 0 && nth.abs() <= 5 => Some(nth),
+                _ => return Err(error()),
+            },
+        };
+
+        Ok(ByDay { nth, weekday })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub by_day: Vec<ByDay>,
+    pub by_month_day: Vec<i32>,
+    pub by_set_pos: Vec<i32>,
+    pub missing_day: MissingDay,
+}
+
+impl Recurrence {
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_set_pos: Vec::new(),
+            missing_day: MissingDay::Skip,
+        }
+    }
+
+    pub fn has_by_rules(&self) -> bool {
+        !self.by_day.is_empty() || !self.by_month_day.is_empty() || !self.by_set_pos.is_empty()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.interval == 0 {
+            return Err("Odstęp powtarzania musi być większy niż 0.".to_string());
+        }
+        if self.by_month_day.iter().any(|day| *day == 0 || day.abs() > 31) {
+            return Err("Dzień miesiąca musi mieścić się w zakresie od 1 do 31 (lub od -31 do -1).".to_string());
+        }
+        if self.by_set_pos.iter().any(|pos| *pos == 0 || pos.abs() > 31) {
+            return Err("Pozycja w okresie musi mieścić się w zakresie od 1 do 31 (lub od -31 do -1).".to_string());
+        }
+        if matches!(self.frequency, Frequency::Daily | Frequency::Weekly) && self.by_day.iter().any(|d| d.nth.is_some()) {
+            return Err("Numer dnia tygodnia (np. -1TH) jest dostępny tylko przy powtarzaniu miesięcznym i rocznym.".to_string());
+        }
+        Ok(())
+    }
+
+    pub fn dates_between(&self, first: NaiveDate, from: NaiveDate, last: NaiveDate) -> Vec<NaiveDate> {
+        let mut dates = Vec::new();
+
+        for index in self.periods_before(first, from).. {
+            let Some((period_start, candidates)) = self.period(first, index) else {
+                break;
+            };
+            if period_start > last {
+                break;
+            }
+            dates.extend(candidates.into_iter().filter(|date| *date >= first && *date >= from && *date <= last));
+        }
+
+        dates
+    }
+
+    fn periods_before(&self, first: NaiveDate, from: NaiveDate) -> u32 {
+        if from <= first {
+            return 0;
+        }
+        let months = (from.year() - first.year()) as i64 * 12 + from.month() as i64 - first.month() as i64;
+        let elapsed = match self.frequency {
+            Frequency::Daily => (from - first).num_days(),
+            Frequency::Weekly => (week_bounds(from).0 - week_bounds(first).0).num_weeks(),
+            Frequency::Monthly => months,
+            Frequency::Yearly => months / 12,
+        };
+        u32::try_from(elapsed / self.interval as i64).unwrap_or(u32::MAX)
+    }
+
+    fn period(&self, first: NaiveDate, index: u32) -> Option<(NaiveDate, Vec<NaiveDate>)> {
+        let step = index.checked_mul(self.interval)?;
+        let (period_start, length) = match self.frequency {
+            Frequency::Daily => (first.checked_add_days(Days::new(step as u64))?, 1),
+            Frequency::Weekly => {
+                let (week_start, _) = week_bounds(first);
+                (week_start.checked_add_days(Days::new(step as u64 * 7))?, 7)
+            }
+            Frequency::Monthly => {
+                let month_start = first.with_day(1)?.checked_add_months(Months::new(step))?;
+                (month_start, days_in_month(month_start))
+            }
+            Frequency::Yearly => {
+                let month_start = first.with_day(1)?.checked_add_months(Months::new(step.checked_mul(12)?))?;
+                (month_start, days_in_month(month_start))
+            }
+        };
+
+        let mut dates: Vec<NaiveDate> = match self.frequency {
+            Frequency::Monthly | Frequency::Yearly if !self.by_month_day.is_empty() || self.by_day.is_empty() => {
+                let days = if self.by_month_day.is_empty() {
+                    vec![first.day() as i32]
+                } else {
+                    self.by_month_day.clone()
+                };
+                days.into_iter()
+                    .filter_map(|day| self.missing_day.resolve(period_start, length, day))
+                    .filter(|date| self.matches_day(*date, first))
+                    .collect()
+            }
+            _ => period_start
+                .iter_days()
+                .take(length as usize)
+                .filter(|date| self.matches_day(*date, first) && self.matches_month_day(*date))
+                .collect(),
+        };
+        dates.sort();
+        dates.dedup();
+
+        if !self.by_set_pos.is_empty() {
+            let len = dates.len() as i32;
+            let mut selected: Vec<NaiveDate> = self
+                .by_set_pos
+                .iter()
+                .map(|pos| if *pos > 0 { pos - 1 } else { len + pos })
+                .filter(|index| (0..len).contains(index))
+                .map(|index| dates[index as usize])
+                .collect();
+            selected.sort();
+            selected.dedup();
+            dates = selected;
+        }
+
+        Some((period_start, dates))
+    }
+
+    fn matches_day(&self, date: NaiveDate, first: NaiveDate) -> bool {
+        if self.by_day.is_empty() {
+            self.frequency != Frequency::Weekly || date.weekday() == first.weekday()
+        } else {
+            let month_length = days_in_month(date);
+            self.by_day.iter().any(|by_day| by_day.matches(date, month_length))
+        }
+    }
+
+    fn matches_month_day(&self, date: NaiveDate) -> bool {
+        let month_length = days_in_month(date) as i32;
+        self.by_month_day.is_empty()
+            || self.by_month_day.iter().any(|day| {
+                let resolved = if *day > 0 { *day } else { month_length + 1 + day };
+                resolved == date.day() as i32
+            })
+    }
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let month_start = date.with_day(1).expect("pierwszy dzień miesiąca");
+    match month_start.checked_add_months(Months::new(1)) {
+        Some(next_month) => (next_month - month_start).num_days() as u32,
+        None => 31,
+    }
+}
+
+fn join<T: ToString>(values: &[T]) -> String {
+    values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",")
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FREQ={}", self.frequency.rrule_name())?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_day.is_empty() {
+            write!(f, ";BYDAY={}", join(&self.by_day))?;
+        }
+        if !self.by_month_day.is_empty() {
+            write!(f, ";BYMONTHDAY={}", join(&self.by_month_day))?;
+        }
+        if !self.by_set_pos.is_empty() {
+            write!(f, ";BYSETPOS={}", join(&self.by_set_pos))?;
+        }
+        if self.missing_day != MissingDay::Skip {
+            write!(f, ";RSCALE=GREGORIAN;SKIP={}", self.missing_day.rrule_name())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut frequency = None;
+        let mut recurrence = Recurrence::new(Frequency::Daily);
+        let list_error = |name: &str| format!("Niepoprawna wartość {} w regule {}.", name, input);
+
+        for part in input.trim().split(';').filter(|part| !part.is_empty()) {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("Niepoprawna reguła powtarzania: {}.", input))?;
+
+            match name.to_uppercase().as_str() {
+                "FREQ" => frequency = Some(value.parse::<Frequency>()?),
+                "INTERVAL" => recurrence.interval = value.parse().map_err(|_| list_error("INTERVAL"))?,
+                "BYDAY" => {
+                    recurrence.by_day = value.split(',').map(str::parse).collect::<Result<_, _>>()?;
+                }
+                "BYMONTHDAY" => {
+                    recurrence.by_month_day = parse_numbers(value).ok_or_else(|| list_error("BYMONTHDAY"))?;
+                }
+                "BYSETPOS" => {
+                    recurrence.by_set_pos = parse_numbers(value).ok_or_else(|| list_error("BYSETPOS"))?;
+                }
+                "RSCALE" if value.eq_ignore_ascii_case("GREGORIAN") => {}
+                "SKIP" => recurrence.missing_day = value.parse()?,
+                _ => return Err(format!("Nieobsługiwana część reguły powtarzania: {}.", part)),
+            }
+        }
+
+        recurrence.frequency = frequency.ok_or_else(|| format!("Brak FREQ w regule {}.", input))?;
+        recurrence.validate()?;
+        Ok(recurrence)
+    }
+}
+
+pub fn parse_numbers(value: &str) -> Option<Vec<i32>> {
+    value.split(',').map(|n| n.trim().parse().ok()).collect()
+}
+
+impl TryFrom<String> for Recurrence {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<Recurrence> for String {
+    fn from(recurrence: Recurrence) -> Self {
+        recurrence.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Local, TimeZone};
+    use crate::model::event::Event;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn rule(input: &str) -> Recurrence {
+        input.parse().unwrap()
+    }
+
+    fn first_dates(rule: &Recurrence, first: NaiveDate, count: usize) -> Vec<NaiveDate> {
+        let mut dates = rule.dates_between(first, first, date(first.year() + 10, 12, 31));
+        dates.truncate(count);
+        dates
+    }
+
+    #[test]
+    fn monthly_on_31st_skips_short_months_by_default() {
+        let dates = first_dates(&rule("FREQ=MONTHLY"), date(2025, 1, 31), 4);
+        assert_eq!(dates, vec![date(2025, 1, 31), date(2025, 3, 31), date(2025, 5, 31), date(2025, 7, 31)]);
+    }
+
+    #[test]
+    fn monthly_on_31st_clamps_without_drifting() {
+        let dates = first_dates(&rule("FREQ=MONTHLY;RSCALE=GREGORIAN;SKIP=BACKWARD"), date(2025, 1, 31), 5);
+        assert_eq!(
+            dates,
+            vec![date(2025, 1, 31), date(2025, 2, 28), date(2025, 3, 31), date(2025, 4, 30), date(2025, 5, 31)]
+        );
+    }
+
+    #[test]
+    fn monthly_on_31st_rolls_to_next_day() {
+        let dates = first_dates(&rule("FREQ=MONTHLY;RSCALE=GREGORIAN;SKIP=FORWARD"), date(2025, 1, 31), 5);
+        assert_eq!(
+            dates,
+            vec![date(2025, 1, 31), date(2025, 3, 1), date(2025, 3, 31), date(2025, 5, 1), date(2025, 5, 31)]
+        );
+    }
+
+    #[test]
+    fn monthly_on_30th_keeps_leap_february_when_clamped() {
+        let dates = first_dates(&rule("FREQ=MONTHLY;RSCALE=GREGORIAN;SKIP=BACKWARD"), date(2024, 1, 30), 3);
+        assert_eq!(dates, vec![date(2024, 1, 30), date(2024, 2, 29), date(2024, 3, 30)]);
+    }
+
+    #[test]
+    fn yearly_leap_day_skips_to_next_leap_year_by_default() {
+        let dates = first_dates(&rule("FREQ=YEARLY"), date(2024, 2, 29), 3);
+        assert_eq!(dates, vec![date(2024, 2, 29), date(2028, 2, 29), date(2032, 2, 29)]);
+    }
+
+    #[test]
+    fn yearly_leap_day_clamps_to_february_28th() {
+        let dates = first_dates(&rule("FREQ=YEARLY;RSCALE=GREGORIAN;SKIP=BACKWARD"), date(2024, 2, 29), 5);
+        assert_eq!(
+            dates,
+            vec![date(2024, 2, 29), date(2025, 2, 28), date(2026, 2, 28), date(2027, 2, 28), date(2028, 2, 29)]
+        );
+    }
+
+    #[test]
+    fn yearly_leap_day_rolls_to_march_1st() {
+        let dates = first_dates(&rule("FREQ=YEARLY;RSCALE=GREGORIAN;SKIP=FORWARD"), date(2024, 2, 29), 3);
+        assert_eq!(dates, vec![date(2024, 2, 29), date(2025, 3, 1), date(2026, 3, 1)]);
+    }
+
+    #[test]
+    fn yearly_interval_is_counted_from_original_start() {
+        let dates = first_dates(&rule("FREQ=YEARLY;INTERVAL=4;RSCALE=GREGORIAN;SKIP=BACKWARD"), date(2023, 2, 28), 3);
+        assert_eq!(dates, vec![date(2023, 2, 28), date(2027, 2, 28), date(2031, 2, 28)]);
+    }
+
+    #[test]
+    fn month_day_list_applies_policy_per_day() {
+        let dates = first_dates(&rule("FREQ=MONTHLY;BYMONTHDAY=15,30;RSCALE=GREGORIAN;SKIP=BACKWARD"), date(2025, 2, 1), 4);
+        assert_eq!(dates, vec![date(2025, 2, 15), date(2025, 2, 28), date(2025, 3, 15), date(2025, 3, 30)]);
+    }
+
+    #[test]
+    fn clamped_month_days_do_not_duplicate() {
+        let dates = first_dates(&rule("FREQ=MONTHLY;BYMONTHDAY=29,30,31;RSCALE=GREGORIAN;SKIP=BACKWARD"), date(2025, 2, 1), 2);
+        assert_eq!(dates, vec![date(2025, 2, 28), date(2025, 3, 29)]);
+    }
+
+    #[test]
+    fn negative_month_day_counts_from_month_end() {
+        let dates = first_dates(&rule("FREQ=MONTHLY;BYMONTHDAY=-1"), date(2024, 1, 1), 3);
+        assert_eq!(dates, vec![date(2024, 1, 31), date(2024, 2, 29), date(2024, 3, 31)]);
+    }
+
+    #[test]
+    fn last_weekday_of_month_ignores_missing_day_policy() {
+        let dates = first_dates(&rule("FREQ=MONTHLY;BYDAY=-1TH"), date(2025, 1, 1), 3);
+        assert_eq!(dates, vec![date(2025, 1, 30), date(2025, 2, 27), date(2025, 3, 27)]);
+    }
+
+    #[test]
+    fn fifth_weekday_is_skipped_in_months_without_one() {
+        let dates = first_dates(&rule("FREQ=MONTHLY;BYDAY=5FR"), date(2025, 1, 1), 2);
+        assert_eq!(dates, vec![date(2025, 1, 31), date(2025, 5, 30)]);
+    }
+
+    #[test]
+    fn set_position_selects_last_working_day() {
+        let dates = first_dates(&rule("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1"), date(2025, 5, 1), 2);
+        assert_eq!(dates, vec![date(2025, 5, 30), date(2025, 6, 30)]);
+    }
+
+    #[test]
+    fn rule_round_trips_through_rrule_text() {
+        let text = "FREQ=MONTHLY;INTERVAL=2;BYMONTHDAY=31;RSCALE=GREGORIAN;SKIP=FORWARD";
+        assert_eq!(rule(text).to_string(), text);
+        assert_eq!(rule("FREQ=MONTHLY;SKIP=OMIT").to_string(), "FREQ=MONTHLY");
+    }
+
+    #[test]
+    fn event_occurrences_keep_time_and_count_from_original_start() {
+        let start = Local.with_ymd_and_hms(2025, 1, 31, 9, 30, 0).unwrap();
+        let event = Event {
+            recurrence: Some(rule("FREQ=MONTHLY;RSCALE=GREGORIAN;SKIP=BACKWARD")),
+            count: Some(4),
+            ..Event::new("Rozliczenie".to_string(), start, start + chrono::Duration::hours(1))
+        };
+
+        let starts: Vec<String> = event
+            .occurrences_between(start, Local.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap())
+            .iter()
+            .map(|e| e.start.format("%Y-%m-%d %H:%M").to_string())
+            .collect();
+        assert_eq!(starts, vec!["2025-01-31 09:30", "2025-02-28 09:30", "2025-03-31 09:30", "2025-04-30 09:30"]);
+    }
+
+    #[test]
+    fn dates_from_later_window_match_full_expansion() {
+        let rule = rule("FREQ=MONTHLY;INTERVAL=5;BYDAY=MO,FR;BYSETPOS=-1;RSCALE=GREGORIAN;SKIP=BACKWARD");
+        let first = date(2004, 3, 12);
+        let (from, last) = (date(2026, 2, 1), date(2027, 12, 31));
+        let full: Vec<NaiveDate> = rule
+            .dates_between(first, first, last)
+            .into_iter()
+            .filter(|d| *d >= from)
+            .collect();
+        assert_eq!(rule.dates_between(first, from, last), full);
+    }
+
+    #[test]
+    fn occurrences_between_only_yields_instances_in_window() {
+        let start = Local.with_ymd_and_hms(2004, 1, 1, 23, 0, 0).unwrap();
+        let mut event = Event {
+            recurrence: Some(rule("FREQ=DAILY")),
+            ..Event::new("Dziennik".to_string(), start, start + chrono::Duration::hours(2))
+        };
+        let moved = Local.with_ymd_and_hms(2026, 3, 4, 12, 0, 0).unwrap();
+        let original = Local.with_ymd_and_hms(2026, 2, 1, 23, 0, 0).unwrap();
+        event.override_occurrence(original, &Event::new("Przeniesione".to_string(), moved, moved));
+
+        let from = Local.with_ymd_and_hms(2026, 3, 2, 0, 0, 0).unwrap();
+        let to = Local.with_ymd_and_hms(2026, 3, 5, 0, 0, 0).unwrap();
+        let starts: Vec<String> = event
+            .occurrences_between(from, to)
+            .iter()
+            .map(|e| e.start.format("%m-%d %H:%M").to_string())
+            .collect();
+        assert_eq!(starts, vec!["03-01 23:00", "03-02 23:00", "03-03 23:00", "03-04 12:00", "03-04 23:00"]);
+    }
+
+    fn warsaw_starts(rule_text: &str, start: &str, days: i64) -> Vec<String> {
+        let zone = chrono_tz::Europe::Warsaw;
+        let start = zone
+            .from_local_datetime(&chrono::NaiveDateTime::parse_from_str(start, "%Y-%m-%d %H:%M").unwrap())
+            .earliest()
+            .unwrap()
+            .with_timezone(&Local);
+        let event = Event {
+            recurrence: Some(rule(rule_text)),
+            time_zone: Some(zone),
+            ..Event::new("Spotkanie".to_string(), start, start + chrono::Duration::hours(1))
+        };
+        event
+            .occurrences_between(start, start + chrono::Duration::days(days))
+            .iter()
+            .map(|e| e.start.with_timezone(&zone).format("%m-%d %H:%M %:z").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn weekly_series_keeps_wall_clock_across_spring_forward() {
+        assert_eq!(
+            warsaw_starts("FREQ=WEEKLY", "2026-03-23 09:00", 20),
+            vec!["03-23 09:00 +01:00", "03-30 09:00 +02:00", "04-06 09:00 +02:00"]
+        );
+    }
+
+    #[test]
+    fn weekly_series_keeps_wall_clock_across_fall_back() {
+        assert_eq!(
+            warsaw_starts("FREQ=WEEKLY", "2026-10-19 09:00", 21),
+            vec!["10-19 09:00 +02:00", "10-26 09:00 +01:00", "11-02 09:00 +01:00"]
+        );
+    }
+
+    #[test]
+    fn daily_series_at_a_nonexistent_time_moves_past_the_gap() {
+        assert_eq!(
+            warsaw_starts("FREQ=DAILY", "2026-03-28 02:30", 2),
+            vec!["03-28 02:30 +01:00", "03-29 03:30 +02:00", "03-30 02:30 +02:00"]
+        );
+    }
+
+    #[test]
+    fn daily_series_at_an_ambiguous_time_occurs_once_per_day() {
+        assert_eq!(
+            warsaw_starts("FREQ=DAILY", "2026-10-24 02:30", 3),
+            vec!["10-24 02:30 +02:00", "10-25 02:30 +02:00", "10-26 02:30 +01:00"]
+        );
+    }
+}
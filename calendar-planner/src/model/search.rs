@@ -0,0 +1,102 @@
+use std::ops::Range;
+use crate::model::event::Event;
+
+#[derive(Clone, PartialEq)]
+pub struct SearchQuery {
+    text: String,
+    folded: Vec<char>,
+}
+
+impl SearchQuery {
+    pub fn new(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let folded: Vec<char> = text.chars().map(fold_char).collect();
+        (!folded.is_empty()).then(|| Self { text: text.to_string(), folded })
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn matches(&self, event: &Event) -> bool {
+        self.matches_text(&event.title)
+            || event.description.as_deref().is_some_and(|text| self.matches_text(text))
+            || event.location.as_deref().is_some_and(|text| self.matches_text(text))
+    }
+
+    pub fn matches_text(&self, text: &str) -> bool {
+        !self.find(text).is_empty()
+    }
+
+    pub fn find(&self, text: &str) -> Vec<Range<usize>> {
+        let chars: Vec<(usize, char)> = text.char_indices().map(|(index, c)| (index, fold_char(c))).collect();
+        let len = self.folded.len();
+
+        let mut ranges = Vec::new();
+        let mut index = 0;
+        while index + len <= chars.len() {
+            if chars[index..index + len].iter().map(|(_, c)| *c).eq(self.folded.iter().copied()) {
+                let end = chars.get(index + len).map_or(text.len(), |(offset, _)| *offset);
+                ranges.push(chars[index].0..end);
+                index += len;
+            } else {
+                index += 1;
+            }
+        }
+        ranges
+    }
+}
+
+fn fold_char(c: char) -> char {
+    match c.to_lowercase().next().unwrap_or(c) {
+        'ą' | 'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' => 'a',
+        'ć' | 'č' | 'ç' => 'c',
+        'ď' => 'd',
+        'ę' | 'é' | 'è' | 'ê' | 'ë' | 'ě' => 'e',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ł' | 'ľ' | 'ĺ' => 'l',
+        'ń' | 'ň' | 'ñ' => 'n',
+        'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ő' => 'o',
+        'ř' => 'r',
+        'ś' | 'š' => 's',
+        'ť' => 't',
+        'ú' | 'ù' | 'û' | 'ü' | 'ů' | 'ű' => 'u',
+        'ý' | 'ÿ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Local, TimeZone};
+
+    fn query(text: &str) -> SearchQuery {
+        SearchQuery::new(text).unwrap()
+    }
+
+    #[test]
+    fn matches_ignore_case_and_polish_diacritics() {
+        let start = Local.with_ymd_and_hms(2026, 12, 31, 20, 0, 0).unwrap();
+        let event = Event::new("Sylwester Marzeń".to_string(), start, start);
+        assert!(query("sylwester").matches(&event));
+        assert!(query("MARZEN").matches(&event));
+        assert!(query("marzeń").matches(&event));
+        assert!(!query("marzenia").matches(&event));
+        assert!(SearchQuery::new("   ").is_none());
+    }
+
+    #[test]
+    fn find_returns_byte_ranges_on_char_boundaries() {
+        let text = "Źródło: żółć i łódź, ŻÓŁĆ";
+        let ranges = query("zolc").find(text);
+        assert_eq!(ranges.len(), 2);
+        for range in &ranges {
+            assert!(text.is_char_boundary(range.start) && text.is_char_boundary(range.end));
+        }
+        assert_eq!(ranges.iter().map(|range| &text[range.clone()]).collect::<Vec<_>>(), vec!["żółć", "ŻÓŁĆ"]);
+        assert_eq!(&text[query("źródło").find(text)[0].clone()], "Źródło");
+        assert_eq!(&text[query("łódź").find(text)[0].clone()], "łódź");
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, Local};
+use clap::ValueEnum;
+use uuid::Uuid;
+use crate::model::event::Event;
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum EditScope {
+    Single,
+    Following,
+    All,
+}
+
+impl EditScope {
+    pub fn label(self) -> &'static str {
+        match self {
+            EditScope::Single => "Tylko to wystąpienie",
+            EditScope::Following => "To i następne",
+            EditScope::All => "Wszystkie wystąpienia",
+        }
+    }
+}
+
+pub fn edit_occurrence(
+    events: &mut Vec<Event>,
+    id: Uuid,
+    occurrence_start: DateTime<Local>,
+    scope: EditScope,
+    mut edited: Event,
+) -> Option<Uuid> {
+    let index = events.iter().position(|e| e.id == id)?;
+    let series = &mut events[index];
+
+    let scope = if series.is_recurring() && occurrence_start == series.start && scope == EditScope::Following {
+        EditScope::All
+    } else if series.is_recurring() {
+        scope
+    } else {
+        EditScope::All
+    };
+
+    match scope {
+        EditScope::Single => {
+            series.override_occurrence(occurrence_start, &edited);
+            Some(id)
+        }
+        EditScope::Following => {
+            let delta = edited.start - occurrence_start;
+            edited.id = Uuid::new_v4();
+            if edited.count.is_some() && edited.count == series.count {
+                edited.count = series
+                    .count
+                    .map(|count| count.saturating_sub(series.occurrences_before(occurrence_start) as u32));
+            }
+            edited.exceptions = series.exceptions.iter().copied().filter(|e| *e >= occurrence_start).collect();
+            edited.overrides = series.overrides.iter().filter(|o| o.original_start >= occurrence_start).cloned().collect();
+            edited.shift_exceptions(delta);
+            series.end_series_before(occurrence_start);
+
+            let new_id = edited.id;
+            events.push(edited);
+            Some(new_id)
+        }
+        EditScope::All => {
+            let delta = edited.start - series.start;
+            edited.id = series.id;
+            edited.uid = series.uid.take();
+            edited.exceptions = std::mem::take(&mut series.exceptions);
+            edited.overrides = std::mem::take(&mut series.overrides);
+            if edited.is_recurring() {
+                edited.shift_exceptions(delta);
+            } else {
+                edited.exceptions.clear();
+                edited.overrides.clear();
+            }
+            *series = edited;
+            Some(id)
+        }
+    }
+}
+
+pub fn delete_occurrence(
+    events: &mut Vec<Event>,
+    id: Uuid,
+    occurrence_start: DateTime<Local>,
+    scope: EditScope,
+) -> bool {
+    let Some(index) = events.iter().position(|e| e.id == id) else {
+        return false;
+    };
+    let series = &mut events[index];
+
+    match scope {
+        EditScope::Single if series.is_recurring() => series.exclude_occurrence(occurrence_start),
+        EditScope::Following if series.is_recurring() && occurrence_start > series.start => {
+            series.end_series_before(occurrence_start)
+        }
+        _ => {
+            events.remove(index);
+        }
+    }
+
+    true
+}
\ No newline at end of file
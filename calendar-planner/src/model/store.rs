@@ -0,0 +1,51 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate};
+use crate::model::event::Event;
+use crate::model::filter::occurrences_between;
+use crate::utils::time::start_of_day;
+
+#[derive(Default)]
+pub struct OccurrenceStore {
+    window: Option<(DateTime<Local>, DateTime<Local>)>,
+    by_start: BTreeMap<DateTime<Local>, Vec<Event>>,
+    longest: Duration,
+}
+
+impl OccurrenceStore {
+    pub fn invalidate(&mut self) {
+        self.window = None;
+    }
+
+    pub fn between(&mut self, events: &[Event], from: DateTime<Local>, to: DateTime<Local>) -> Vec<&Event> {
+        if to <= from {
+            return Vec::new();
+        }
+        if !self.window.is_some_and(|(start, end)| start <= from && to <= end) {
+            self.rebuild(events, whole_years(from, to));
+        }
+
+        self.by_start
+            .range(from - self.longest..to)
+            .flat_map(|(_, occurrences)| occurrences)
+            .filter(|e| e.end > from || e.start >= from)
+            .collect()
+    }
+
+    fn rebuild(&mut self, events: &[Event], (from, to): (DateTime<Local>, DateTime<Local>)) {
+        self.by_start.clear();
+        self.longest = Duration::zero();
+        for occurrence in occurrences_between(events, from, to) {
+            self.longest = self.longest.max(occurrence.end - occurrence.start);
+            self.by_start.entry(occurrence.start).or_default().push(occurrence);
+        }
+        self.window = Some((from, to));
+    }
+}
+
+fn whole_years(from: DateTime<Local>, to: DateTime<Local>) -> (DateTime<Local>, DateTime<Local>) {
+    let year_start = |year: i32| NaiveDate::from_ymd_opt(year, 1, 1).map(|date| start_of_day(None, date));
+    (
+        year_start(from.year()).map_or(from, |start| start.min(from)),
+        year_start(to.year() + 1).map_or(to, |end| end.max(to)),
+    )
+}
@@ -0,0 +1,188 @@
+use serde_json::{json, Value};
+use uuid::Uuid;
+
+pub const CURRENT_VERSION: u64 = 3;
+const LEGACY_VERSION: u64 = 1;
+
+pub enum Layout {
+    Known(u64),
+    Unsupported(u64),
+    Unrecognized,
+}
+
+pub fn detect_version(value: &Value) -> Layout {
+    let version = match value {
+        Value::Array(_) => LEGACY_VERSION,
+        Value::Object(map) => match map.get("version").and_then(Value::as_u64) {
+            Some(version) => version,
+            None => return Layout::Unrecognized,
+        },
+        _ => return Layout::Unrecognized,
+    };
+
+    if (LEGACY_VERSION..=CURRENT_VERSION).contains(&version) {
+        Layout::Known(version)
+    } else {
+        Layout::Unsupported(version)
+    }
+}
+
+pub fn migrate(mut value: Value, from: u64) -> Value {
+    for version in from..CURRENT_VERSION {
+        value = match version {
+            1 => wrap_legacy_array(value),
+            2 => convert_recurrence_presets(value),
+            _ => unreachable!("brak migracji z wersji {}", version),
+        };
+    }
+    value
+}
+
+fn wrap_legacy_array(value: Value) -> Value {
+    let events: Vec<Value> = match value {
+        Value::Array(items) => items.into_iter().map(with_id).collect(),
+        _ => Vec::new(),
+    };
+
+    json!({ "version": 2, "events": events })
+}
+
+fn with_id(mut item: Value) -> Value {
+    if let Value::Object(ref mut map) = item {
+        map.entry("id").or_insert_with(|| Value::String(Uuid::new_v4().to_string()));
+    }
+    item
+}
+
+fn convert_recurrence_presets(mut value: Value) -> Value {
+    if let Some(Value::Array(events)) = value.get_mut("events") {
+        for event in events.iter_mut().filter_map(Value::as_object_mut) {
+            let rule = match event.get("recurrence").and_then(Value::as_str) {
+                Some("Daily") => Some("FREQ=DAILY"),
+                Some("Weekly") => Some("FREQ=WEEKLY"),
+                Some("Biweekly") => Some("FREQ=WEEKLY;INTERVAL=2"),
+                Some("Monthly") => Some("FREQ=MONTHLY"),
+                Some("Yearly") => Some("FREQ=YEARLY"),
+                _ => None,
+            };
+            match rule {
+                Some(rule) => event.insert("recurrence".to_string(), Value::String(rule.to_string())),
+                None => event.remove("recurrence"),
+            };
+        }
+    }
+    value["version"] = json!(3);
+    value
+}
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use super::*;
+    use crate::storage::{EventFile, Storage, StorageError};
+
+    fn legacy_event(title: &str, recurrence: Option<&str>) -> Value {
+        json!({
+            "title": title,
+            "start": "2024-03-04T10:00:00+01:00",
+            "end": "2024-03-04T11:00:00+01:00",
+            "color": null,
+            "recurrence": recurrence,
+        })
+    }
+
+    #[test]
+    fn bare_v1_array_gets_ids_and_envelope() {
+        let value = json!([legacy_event("Spotkanie", None), legacy_event("Przegląd", None)]);
+        assert!(matches!(detect_version(&value), Layout::Known(1)));
+
+        let migrated = migrate(value, 1);
+        assert_eq!(migrated["version"], json!(CURRENT_VERSION));
+        let events = migrated["events"].as_array().unwrap();
+        let ids: Vec<&str> = events.iter().map(|event| event["id"].as_str().unwrap()).collect();
+        assert!(ids.iter().all(|id| Uuid::parse_str(id).is_ok()));
+        assert_ne!(ids[0], ids[1]);
+
+        let file: EventFile = serde_json::from_value(migrated).unwrap();
+        assert_eq!(file.events[1].title, "Przegląd");
+    }
+
+    #[test]
+    fn v2_presets_become_rrules() {
+        let events: Vec<Value> = ["Daily", "Weekly", "Biweekly", "Monthly", "Yearly", "None"]
+            .iter()
+            .map(|preset| with_id(legacy_event(preset, Some(preset))))
+            .collect();
+        let id = events[0]["id"].clone();
+        let value = json!({ "version": 2, "events": events });
+        assert!(matches!(detect_version(&value), Layout::Known(2)));
+
+        let migrated = migrate(value, 2);
+        let rules: Vec<Option<&str>> = migrated["events"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|event| event.get("recurrence").and_then(Value::as_str))
+            .collect();
+        assert_eq!(
+            rules,
+            [
+                Some("FREQ=DAILY"),
+                Some("FREQ=WEEKLY"),
+                Some("FREQ=WEEKLY;INTERVAL=2"),
+                Some("FREQ=MONTHLY"),
+                Some("FREQ=YEARLY"),
+                None,
+            ]
+        );
+        assert_eq!(migrated["events"][0]["id"], id);
+
+        let file: EventFile = serde_json::from_value(migrated).unwrap();
+        assert_eq!(file.events[2].recurrence.as_ref().map(ToString::to_string).as_deref(), Some("FREQ=WEEKLY;INTERVAL=2"));
+        assert!(file.events[5].recurrence.is_none());
+    }
+
+    #[test]
+    fn loading_a_legacy_file_does_not_rewrite_it() {
+        let path = std::env::temp_dir().join(format!("calendar-planner-{}.json", Uuid::new_v4()));
+        let content = json!([legacy_event("Spotkanie", None)]).to_string();
+        fs::write(&path, &content).unwrap();
+        let storage = Storage::new(path.clone(), 2);
+
+        let events = storage.load().unwrap();
+        let untouched = fs::read_to_string(&path).unwrap();
+        let backup_after_load = storage.backups().len();
+        storage.save(&events).unwrap();
+        let saved: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let backup = fs::read_to_string(&storage.backups()[0].path).unwrap();
+        for backup in storage.backups() {
+            fs::remove_file(backup.path).unwrap();
+        }
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(events[0].title, "Spotkanie");
+        assert_eq!(untouched, content);
+        assert_eq!(backup_after_load, 0);
+        assert_eq!(saved["version"], json!(CURRENT_VERSION));
+        assert_eq!(backup, content);
+    }
+
+    #[test]
+    fn newer_version_is_refused() {
+        let value = json!({ "version": CURRENT_VERSION + 1, "events": [] });
+        assert!(matches!(detect_version(&value), Layout::Unsupported(version) if version == CURRENT_VERSION + 1));
+        assert!(matches!(detect_version(&json!({ "events": [] })), Layout::Unrecognized));
+
+        let path = std::env::temp_dir().join(format!("calendar-planner-{}.json", Uuid::new_v4()));
+        let content = value.to_string();
+        fs::write(&path, &content).unwrap();
+        let storage = Storage::new(path.clone(), 0);
+        let loaded = storage.load();
+        let saved = storage.save(&[]);
+        let after = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(loaded, Err(StorageError::UnsupportedVersion { version, .. }) if version == CURRENT_VERSION + 1));
+        assert!(matches!(saved, Err(StorageError::UnsupportedVersion { .. })));
+        assert_eq!(after, content);
+    }
+}
@@ -0,0 +1,316 @@
+use std::{env, fs::{self, File}, io::{self, BufReader, BufWriter, Write}, path::{Path, PathBuf}};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use crate::model::event::Event;
+use migrations::{Layout, CURRENT_VERSION};
+
+mod migrations;
+
+const FILE_NAME: &str = "events.json";
+pub const APP_DIR: &str = "calendar-planner";
+pub const FILE_ENV_VAR: &str = "CALENDAR_PLANNER_FILE";
+
+#[derive(Debug, Error)]
+pub enum StorageError {
+    #[error("Nie udało się odczytać pliku {}: {source}", path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("Plik {} jest uszkodzony: {source}", path.display())]
+    Corrupt { path: PathBuf, source: serde_json::Error },
+    #[error("Nie udało się zapisać pliku {}: {source}", path.display())]
+    Write { path: PathBuf, source: io::Error },
+    #[error("Nie udało się zapisać wydarzeń: {0}")]
+    Serialize(#[from] serde_json::Error),
+    #[error("Nie udało się utworzyć kopii zapasowej pliku {}: {source}", path.display())]
+    Backup { path: PathBuf, source: io::Error },
+    #[error("Kopia zapasowa nr {0} nie istnieje.")]
+    MissingBackup(usize),
+    #[error(
+        "Plik {} ma wersję {version}, a ta wersja programu obsługuje najwyżej wersję {CURRENT_VERSION}. Zaktualizuj program.",
+        path.display()
+    )]
+    UnsupportedVersion { path: PathBuf, version: u64 },
+    #[error("Plik {} nie zawiera listy wydarzeń w znanym formacie.", path.display())]
+    UnknownLayout { path: PathBuf },
+}
+
+#[derive(Serialize)]
+struct EventFileRef<'a> {
+    version: u64,
+    events: &'a [Event],
+}
+
+#[derive(Deserialize)]
+struct EventFile {
+    events: Vec<Event>,
+}
+
+pub struct Backup {
+    pub number: usize,
+    pub path: PathBuf,
+    pub modified: Option<DateTime<Local>>,
+    pub event_count: Option<usize>,
+}
+
+pub struct Storage {
+    pub path: PathBuf,
+    pub backup_count: usize,
+}
+
+impl Storage {
+    pub fn new(path: PathBuf, backup_count: usize) -> Self {
+        Self { path, backup_count }
+    }
+
+    pub fn load(&self) -> Result<Vec<Event>, StorageError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        read_events(&self.path)
+    }
+
+    pub fn save(&self, events: &[Event]) -> Result<(), StorageError> {
+        let write_error = |source| StorageError::Write { path: self.path.clone(), source };
+
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(write_error)?;
+        }
+
+        if self.path.exists() {
+            match read_events(&self.path) {
+                Ok(_) => self.rotate_backups()?,
+                Err(err @ StorageError::UnsupportedVersion { .. }) => return Err(err),
+                Err(_) => {
+                    self.backup_unreadable_file()?;
+                }
+            }
+        }
+
+        let temp_path = self.sibling_path(".tmp");
+        let file = File::create(&temp_path).map_err(write_error)?;
+        let mut writer = BufWriter::new(file);
+        serde_json::to_writer_pretty(&mut writer, &EventFileRef { version: CURRENT_VERSION, events })?;
+        writer.flush().map_err(write_error)?;
+        writer.get_ref().sync_all().map_err(write_error)?;
+        drop(writer);
+
+        fs::rename(&temp_path, &self.path).map_err(write_error)?;
+        self.sync_parent_dir();
+        Ok(())
+    }
+
+    pub fn backup_unreadable_file(&self) -> Result<PathBuf, StorageError> {
+        let backup = self.sibling_path(&format!(".corrupt-{}", Local::now().format("%Y%m%d-%H%M%S")));
+        fs::rename(&self.path, &backup).map_err(|source| StorageError::Backup { path: self.path.clone(), source })?;
+        Ok(backup)
+    }
+
+    pub fn backups(&self) -> Vec<Backup> {
+        (1..=self.backup_count)
+            .map(|number| (number, self.backup_path(number)))
+            .filter(|(_, path)| path.exists())
+            .map(|(number, path)| Backup {
+                number,
+                modified: fs::metadata(&path)
+                    .and_then(|m| m.modified())
+                    .ok()
+                    .map(DateTime::<Local>::from),
+                event_count: read_events(&path).ok().map(|events| events.len()),
+                path,
+            })
+            .collect()
+    }
+
+    pub fn restore(&self, number: usize) -> Result<Vec<Event>, StorageError> {
+        let path = self.backup_path(number);
+        if number == 0 || number > self.backup_count || !path.exists() {
+            return Err(StorageError::MissingBackup(number));
+        }
+
+        let events = read_events(&path)?;
+        self.save(&events)?;
+        Ok(events)
+    }
+
+    fn rotate_backups(&self) -> Result<(), StorageError> {
+        if self.backup_count == 0 {
+            return Ok(());
+        }
+        let backup_error = |source| StorageError::Backup { path: self.path.clone(), source };
+
+        let oldest = self.backup_path(self.backup_count);
+        if oldest.exists() {
+            fs::remove_file(&oldest).map_err(backup_error)?;
+        }
+        for number in (1..self.backup_count).rev() {
+            let from = self.backup_path(number);
+            if from.exists() {
+                fs::rename(&from, self.backup_path(number + 1)).map_err(backup_error)?;
+            }
+        }
+        fs::copy(&self.path, self.backup_path(1)).map_err(backup_error)?;
+        Ok(())
+    }
+
+    fn backup_path(&self, number: usize) -> PathBuf {
+        self.sibling_path(&format!(".{}", number))
+    }
+
+    fn sibling_path(&self, suffix: &str) -> PathBuf {
+        let mut path = self.path.as_os_str().to_owned();
+        path.push(suffix);
+        PathBuf::from(path)
+    }
+
+    fn sync_parent_dir(&self) {
+        #[cfg(unix)]
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty())
+            && let Ok(dir) = File::open(parent)
+        {
+            let _ = dir.sync_all();
+        }
+    }
+}
+
+pub fn resolve_path(cli_file: Option<PathBuf>) -> PathBuf {
+    if let Some(path) = cli_file {
+        return path;
+    }
+
+    if let Some(path) = env::var_os(FILE_ENV_VAR).filter(|p| !p.is_empty()) {
+        return PathBuf::from(path);
+    }
+
+    match dirs::data_dir() {
+        Some(dir) => dir.join(APP_DIR).join(FILE_NAME),
+        None => PathBuf::from(FILE_NAME),
+    }
+}
+
+fn read_events(path: &Path) -> Result<Vec<Event>, StorageError> {
+    let value = read_json(path)?;
+    let value = match migrations::detect_version(&value) {
+        Layout::Known(CURRENT_VERSION) => value,
+        Layout::Known(version) => migrations::migrate(value, version),
+        Layout::Unsupported(version) => {
+            return Err(StorageError::UnsupportedVersion { path: path.to_path_buf(), version });
+        }
+        Layout::Unrecognized => return Err(StorageError::UnknownLayout { path: path.to_path_buf() }),
+    };
+
+    let file: EventFile = serde_json::from_value(value)
+        .map_err(|source| StorageError::Corrupt { path: path.to_path_buf(), source })?;
+    Ok(file.events)
+}
+
+fn read_json(path: &Path) -> Result<serde_json::Value, StorageError> {
+    let file = File::open(path).map_err(|source| StorageError::Read { path: path.to_path_buf(), source })?;
+    let reader = BufReader::new(file);
+    serde_json::from_reader(reader).map_err(|source| StorageError::Corrupt { path: path.to_path_buf(), source })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    struct Scratch {
+        dir: PathBuf,
+        storage: Storage,
+    }
+
+    impl Scratch {
+        fn new(backup_count: usize) -> Self {
+            let dir = env::temp_dir().join(format!("calendar-planner-{}", Uuid::new_v4()));
+            fs::create_dir_all(&dir).unwrap();
+            let storage = Storage::new(dir.join(FILE_NAME), backup_count);
+            Self { dir, storage }
+        }
+
+        fn files(&self) -> Vec<String> {
+            let mut names: Vec<String> = fs::read_dir(&self.dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+                .collect();
+            names.sort();
+            names
+        }
+    }
+
+    impl Drop for Scratch {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.dir);
+        }
+    }
+
+    fn titles(events: &[Event]) -> Vec<&str> {
+        events.iter().map(|e| e.title.as_str()).collect()
+    }
+
+    fn generation(number: usize) -> Vec<Event> {
+        let start = Local::now();
+        vec![Event::new(format!("Wersja {}", number), start, start)]
+    }
+
+    #[test]
+    fn save_replaces_the_file_through_a_temp_file() {
+        let scratch = Scratch::new(0);
+        fs::write(scratch.storage.sibling_path(".tmp"), "resztki przerwanego zapisu").unwrap();
+
+        scratch.storage.save(&generation(1)).unwrap();
+
+        assert_eq!(scratch.files(), [FILE_NAME]);
+        assert_eq!(titles(&scratch.storage.load().unwrap()), ["Wersja 1"]);
+        let saved: serde_json::Value = serde_json::from_str(&fs::read_to_string(&scratch.storage.path).unwrap()).unwrap();
+        assert_eq!(saved["version"], CURRENT_VERSION);
+    }
+
+    #[test]
+    fn rotation_keeps_backup_count_copies() {
+        let scratch = Scratch::new(2);
+        for number in 1..=4 {
+            scratch.storage.save(&generation(number)).unwrap();
+        }
+
+        assert_eq!(scratch.files(), ["events.json", "events.json.1", "events.json.2"]);
+        let backups = scratch.storage.backups();
+        assert_eq!(backups.iter().map(|b| b.number).collect::<Vec<_>>(), [1, 2]);
+        assert!(backups.iter().all(|b| b.event_count == Some(1)));
+        assert_eq!(titles(&read_events(&backups[0].path).unwrap()), ["Wersja 3"]);
+        assert_eq!(titles(&read_events(&backups[1].path).unwrap()), ["Wersja 2"]);
+    }
+
+    #[test]
+    fn restore_brings_back_a_backup_and_keeps_the_current_file() {
+        let scratch = Scratch::new(3);
+        for number in 1..=3 {
+            scratch.storage.save(&generation(number)).unwrap();
+        }
+
+        let restored = scratch.storage.restore(2).unwrap();
+        assert_eq!(titles(&restored), ["Wersja 1"]);
+        assert_eq!(titles(&scratch.storage.load().unwrap()), ["Wersja 1"]);
+        assert_eq!(titles(&read_events(&scratch.storage.backup_path(1)).unwrap()), ["Wersja 3"]);
+
+        assert!(matches!(scratch.storage.restore(0), Err(StorageError::MissingBackup(0))));
+        assert!(matches!(scratch.storage.restore(4), Err(StorageError::MissingBackup(4))));
+    }
+
+    #[test]
+    fn unreadable_file_is_backed_up_before_writing() {
+        let scratch = Scratch::new(2);
+        fs::write(&scratch.storage.path, "{ to nie jest JSON").unwrap();
+        assert!(matches!(scratch.storage.load(), Err(StorageError::Corrupt { .. })));
+
+        scratch.storage.save(&generation(1)).unwrap();
+
+        let files = scratch.files();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0], FILE_NAME);
+        assert!(files[1].starts_with("events.json.corrupt-"));
+        assert_eq!(fs::read_to_string(scratch.dir.join(&files[1])).unwrap(), "{ to nie jest JSON");
+        assert_eq!(titles(&scratch.storage.load().unwrap()), ["Wersja 1"]);
+        assert!(scratch.storage.backups().is_empty());
+    }
+}
@@ -0,0 +1,43 @@
+use ratatui::{
+    prelude::*,
+    widgets::*
+};
+use crate::model::event::Event;
+use crate::model::search::SearchQuery;
+use crate::utils::parse::{format_event_time, format_original_time};
+use super::highlight_matches;
+
+pub fn render_details(f: &mut Frame, area: Rect, event: Option<&Event>, query: Option<&SearchQuery>) {
+    let block = Block::default().title("Szczegóły [o]").borders(Borders::ALL);
+    let Some(event) = event else {
+        let placeholder = Paragraph::new("Brak wybranego wydarzenia.").style(Style::default().fg(Color::Gray));
+        f.render_widget(placeholder.block(block), area);
+        return;
+    };
+
+    let label = |text: &'static str| Span::styled(text, Style::default().fg(Color::Gray));
+    let mut lines = vec![
+        Line::from(highlight_matches(&event.title, query)).style(Style::default().add_modifier(Modifier::BOLD)),
+        Line::from(vec![label("Termin: "), Span::raw(format_event_time(event))]),
+    ];
+    if let Some(time) = format_original_time(event.start, event.time_zone) {
+        lines.push(Line::from(vec![label("W strefie wydarzenia: "), Span::raw(time)]));
+    }
+    if let Some(ref location) = event.location {
+        let mut spans = vec![label("Miejsce: ")];
+        spans.extend(highlight_matches(location, query));
+        lines.push(Line::from(spans));
+    }
+    if let Some(ref url) = event.url {
+        lines.push(Line::from(vec![
+            label("URL: "),
+            Span::styled(url.clone(), Style::default().fg(Color::Blue).add_modifier(Modifier::UNDERLINED)),
+        ]));
+    }
+    if let Some(ref description) = event.description {
+        lines.push(Line::raw(""));
+        lines.extend(description.lines().map(|line| Line::from(highlight_matches(line, query))));
+    }
+
+    f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }).block(block), area);
+}
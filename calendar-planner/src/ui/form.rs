@@ -0,0 +1,306 @@
+use ratatui::{
+    prelude::*,
+    widgets::*
+};
+use crossterm::event::KeyCode;
+use chrono::{DateTime, Duration, Local};
+use crate::model::event::Event;
+use crate::utils::parse::{
+    end_after, format_duration, format_event_time, format_recurrence, format_start, format_until, parse_count, parse_date, parse_days,
+    parse_duration, parse_recurrence, parse_start_in, parse_text, parse_time_zone, parse_until, parse_url, DATE_FORMAT,
+};
+use crate::utils::time::local_time_zone;
+
+const FIELD_COUNT: usize = 12;
+const ALL_DAY_FIELD: usize = 1;
+const DESCRIPTION_FIELD: usize = 8;
+const FIELD_HEIGHT: u16 = 3;
+const DESCRIPTION_HEIGHT: u16 = 6;
+
+pub struct EventForm {
+    title: String,
+    all_day: bool,
+    start: String,
+    time_zone: String,
+    duration: String,
+    color: String,
+    location: String,
+    url: String,
+    description: String,
+    recurrence: String,
+    until: String,
+    count: String,
+    pub active_field: usize,
+    pub error_message: Option<String>,
+    pub conflict_warning: Option<String>,
+}
+
+impl EventForm {
+    pub fn new() -> Self {
+        Self {
+            title: String::new(),
+            all_day: false,
+            start: String::new(),
+            time_zone: local_time_zone().map(|zone| zone.name().to_string()).unwrap_or_default(),
+            duration: String::new(),
+            color: String::new(),
+            location: String::new(),
+            url: String::new(),
+            description: String::new(),
+            recurrence: String::new(),
+            until: String::new(),
+            count: String::new(),
+            active_field: 0,
+            error_message: None,
+            conflict_warning: None,
+        }
+    }
+
+    fn fields(&self) -> [(&'static str, &str); FIELD_COUNT] {
+        [
+            ("Tytuł", &self.title),
+            ("Cały dzień (spacja przełącza)", if self.all_day { "[x] tak" } else { "[ ] nie" }),
+            if self.all_day {
+                ("Data rozpoczęcia (RRRR-MM-DD)", &self.start)
+            } else {
+                ("Data rozpoczęcia (RRRR-MM-DD HH:MM)", &self.start)
+            },
+            ("Strefa czasowa (np. Europe/Warsaw, America/New_York; pusta = lokalna)", &self.time_zone),
+            if self.all_day {
+                ("Liczba dni", &self.duration)
+            } else {
+                ("Czas trwania (np. 1.5 lub 1:30)", &self.duration)
+            },
+            ("Kolor (opcjonalnie)", &self.color),
+            ("Miejsce (opcjonalnie)", &self.location),
+            ("Adres URL (opcjonalnie, np. https://...)", &self.url),
+            ("Opis (opcjonalnie; Enter: nowy wiersz, strzałka w dół: następne pole)", &self.description),
+            ("Powtarzanie (np. weekly/3, weekly mo,we,fr, monthly -1th, monthly 31 clamp/roll)", &self.recurrence),
+            ("Powtarzaj do (RRRR-MM-DD, opcjonalnie)", &self.until),
+            ("Liczba wystąpień (opcjonalnie)", &self.count),
+        ]
+    }
+
+    fn field_mut(&mut self, index: usize) -> Option<&mut String> {
+        match index {
+            0 => Some(&mut self.title),
+            2 => Some(&mut self.start),
+            3 => Some(&mut self.time_zone),
+            4 => Some(&mut self.duration),
+            5 => Some(&mut self.color),
+            6 => Some(&mut self.location),
+            7 => Some(&mut self.url),
+            DESCRIPTION_FIELD => Some(&mut self.description),
+            9 => Some(&mut self.recurrence),
+            10 => Some(&mut self.until),
+            11 => Some(&mut self.count),
+            _ => None,
+        }
+    }
+
+    fn toggle_all_day(&mut self) {
+        self.all_day = !self.all_day;
+        if self.all_day {
+            if let Some((date, _)) = self.start.trim().split_once(' ') {
+                self.start = date.to_string();
+            }
+            self.duration = "1".to_string();
+        } else {
+            if parse_date(&self.start).is_ok() {
+                self.start = format!("{} 09:00", self.start.trim());
+            }
+            self.duration = "1:00".to_string();
+        }
+    }
+
+    pub fn is_last_field(&self) -> bool {
+        self.active_field == FIELD_COUNT - 1
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let available = area.height.saturating_sub(FIELD_HEIGHT);
+        let mut first = self.active_field;
+        let mut used = field_height(first);
+        while first > 0 && used + field_height(first - 1) <= available {
+            first -= 1;
+            used += field_height(first);
+        }
+
+        let mut y = area.y;
+        for (index, (label, value)) in self.fields().into_iter().enumerate().skip(first) {
+            let height = field_height(index);
+            if index > self.active_field && y + height > area.y + available {
+                break;
+            }
+            let style = if self.active_field == index {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default()
+            };
+            let hidden_lines = (value.lines().count() as u16).saturating_sub(height - 2);
+            let paragraph = Paragraph::new(value)
+                .scroll((hidden_lines, 0))
+                .style(style)
+                .block(Block::default().title(label).borders(Borders::ALL).border_style(style));
+            let field_area = Rect::new(area.x, y, area.width, height);
+            f.render_widget(paragraph, field_area.intersection(area));
+            y += height;
+        }
+
+        if let Some(ref msg) = self.error_message {
+            let error_area = Rect {
+                x: area.x,
+                y: area.y + area.height.saturating_sub(3),
+                width: area.width,
+                height: 3,
+            };
+            let paragraph = Paragraph::new(msg.as_str())
+                .style(Style::default().fg(Color::Red))
+                .block(Block::default().title("Błąd").borders(Borders::ALL));
+            f.render_widget(paragraph, error_area);
+        } else if let Some(ref warning) = self.conflict_warning {
+            let warning_area = Rect {
+                x: area.x,
+                y: area.y + area.height.saturating_sub(4),
+                width: area.width,
+                height: 4,
+            };
+            let paragraph = Paragraph::new(warning.as_str())
+                .wrap(Wrap { trim: true })
+                .style(Style::default().fg(Color::Yellow))
+                .block(Block::default().title("Konflikt terminów").borders(Borders::ALL));
+            f.render_widget(paragraph, warning_area);
+        }
+    }
+
+    pub fn set_conflicts(&mut self, conflicts: &[Event]) {
+        let mut listed: Vec<String> = conflicts
+            .iter()
+            .take(3)
+            .map(|e| format!("{} ({})", e.title, format_event_time(e)))
+            .collect();
+        if conflicts.len() > listed.len() {
+            listed.push(format!("i {} innych", conflicts.len() - listed.len()));
+        }
+        self.conflict_warning = Some(format!(
+            "Nakłada się na: {}. [Enter] Zapisz mimo to | [Esc] Anuluj",
+            listed.join(", ")
+        ));
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Char(' ') if self.active_field == ALL_DAY_FIELD => {
+                self.error_message = None;
+                self.conflict_warning = None;
+                self.toggle_all_day();
+            }
+            KeyCode::Char(c) => {
+                self.error_message = None;
+                self.conflict_warning = None;
+                if let Some(field) = self.field_mut(self.active_field) {
+                    field.push(c);
+                }
+            }
+            KeyCode::Backspace => {
+                self.error_message = None;
+                self.conflict_warning = None;
+                if let Some(field) = self.field_mut(self.active_field) {
+                    field.pop();
+                }
+            }
+            KeyCode::Enter if self.active_field == DESCRIPTION_FIELD => {
+                self.error_message = None;
+                self.conflict_warning = None;
+                self.description.push('\n');
+            }
+            KeyCode::Enter => {
+                self.active_field = (self.active_field + 1) % FIELD_COUNT;
+            }
+            KeyCode::Up if self.active_field > 0 => {
+                self.active_field -= 1;
+            }
+            KeyCode::Down if self.active_field < FIELD_COUNT - 1 => {
+                self.active_field += 1;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn get_event(&self) -> Option<Event> {
+        self.build().ok()
+    }
+
+    pub fn from_event(event: &Event) -> Self {
+        let (start, duration) = if event.all_day {
+            (event.start_date().format(DATE_FORMAT).to_string(), event.day_count().to_string())
+        } else {
+            (format_start(event.start, event.time_zone), format_duration(event.end - event.start))
+        };
+
+        Self {
+            title: event.title.clone(),
+            all_day: event.all_day,
+            start,
+            time_zone: event.time_zone.map(|zone| zone.name().to_string()).unwrap_or_default(),
+            duration,
+            color: event.color.clone().unwrap_or_default(),
+            location: event.location.clone().unwrap_or_default(),
+            url: event.url.clone().unwrap_or_default(),
+            description: event.description.clone().unwrap_or_default(),
+            recurrence: event.recurrence.as_ref().map(format_recurrence).unwrap_or_default(),
+            until: event.until.map(|until| format_until(until, event.time_zone)).unwrap_or_default(),
+            count: event.count.map(|c| c.to_string()).unwrap_or_default(),
+            active_field: 0,
+            error_message: None,
+            conflict_warning: None,
+        }
+    }
+
+    pub fn from_slot(start: DateTime<Local>, duration: Duration) -> Self {
+        Self {
+            start: format_start(start, local_time_zone()),
+            duration: format_duration(duration),
+            ..Self::new()
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        self.build().map(|_| ())
+    }
+
+    fn build(&self) -> Result<Event, String> {
+        if self.title.trim().is_empty() {
+            return Err("Tytuł nie może być pusty.".to_string());
+        }
+
+        let time_zone = parse_time_zone(&self.time_zone)?;
+        let base = if self.all_day {
+            Event::new_all_day(self.title.clone(), time_zone, parse_date(&self.start)?, parse_days(&self.duration)?)
+        } else {
+            let start = parse_start_in(&self.start, time_zone)?;
+            Event::new(self.title.clone(), start, end_after(start, parse_duration(&self.duration)?)?)
+        };
+
+        let until = parse_until(&self.until, time_zone)?;
+        if until.is_some_and(|until| until < base.start) {
+            return Err("Data końca powtarzania nie może być wcześniejsza niż początek.".to_string());
+        }
+
+        Ok(Event {
+            color: parse_text(&self.color),
+            description: parse_text(&self.description),
+            location: parse_text(&self.location),
+            url: parse_url(&self.url)?,
+            time_zone,
+            recurrence: parse_recurrence(&self.recurrence)?,
+            until,
+            count: parse_count(&self.count)?,
+            ..base
+        })
+    }
+}
+
+fn field_height(index: usize) -> u16 {
+    if index == DESCRIPTION_FIELD { DESCRIPTION_HEIGHT } else { FIELD_HEIGHT }
+}
@@ -1,5 +1,6 @@
+use std::collections::HashSet;
 use std::io;
-use std::thread::current;
+use std::path::Path;
 use ratatui::{
     prelude::*,
     widgets::*
@@ -8,290 +9,340 @@ use crossterm::event;
 use crossterm::event::{KeyCode, EnableMouseCapture, DisableMouseCapture, KeyEventKind};
 use crossterm::execute;
 use crossterm::terminal::{enable_raw_mode, disable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
-use chrono::{DateTime, Datelike, Duration, Local, NaiveDateTime, TimeZone, Months};
-use crate::model::event::{Event, Recurrence};
+use chrono::{DateTime, Duration, Local, Months, NaiveDate};
+use uuid::Uuid;
+use crate::config::Config;
+use crate::ical;
+use crate::model::conflict::{find_conflicts, occurrence_key, overlapping, OccurrenceKey};
+use crate::model::event::Event;
+use crate::storage::{Storage, StorageError};
+use crate::model::series::{delete_occurrence, edit_occurrence, EditScope};
+use crate::model::filter::{page_around, visible_occurrences, week_bounds, EventFilter, ViewMode};
+use crate::model::free::{free_slots, Slot};
+use crate::model::query::FilterQuery;
+use crate::model::search::SearchQuery;
+use crate::model::store::OccurrenceStore;
 use crate::utils::color::parse_color;
-
-struct EventForm {
-    title: String,
-    start: String,
-    duration: String,
-    color: String,
-    recurrence: String,
-    active_field: usize,
-    error_message: Option<String>,
+use crate::utils::parse::{format_event_time, format_original_time, format_slot, parse_duration};
+use crate::utils::time::start_of_day;
+use details::render_details;
+use form::EventForm;
+use month::render_month;
+use timeline::{render_timeline, Highlight};
+use year::render_year;
+
+mod details;
+mod form;
+mod month;
+mod timeline;
+mod year;
+
+const WEEKDAYS: [&str; 7] = ["Pn", "Wt", "Śr", "Cz", "Pt", "So", "Nd"];
+
+#[derive(Clone, Copy)]
+enum FileAction {
+    Import,
+    Export,
 }
 
-impl EventForm {
-    fn new() -> Self {
-        Self {
-            title: String::new(),
-            start: String::new(),
-            duration: String::new(),
-            color: String::new(),
-            recurrence: String::new(),
-            active_field: 0,
-            error_message: None,
+impl FileAction {
+    fn execute(self, path: &Path, events: &mut Vec<Event>, storage: &Storage) -> Result<String, Box<dyn std::error::Error>> {
+        match self {
+            FileAction::Import => {
+                let summary = ical::import_from_file(path, events)?;
+                storage.save(events)?;
+                Ok(summary.to_string())
+            }
+            FileAction::Export => {
+                ical::export_to_file(path, events)?;
+                Ok(format!("Wyeksportowano {} wydarzeń do {}.", events.len(), path.display()))
+            }
         }
     }
+}
 
-    fn render(&self, f: &mut Frame, area: Rect) {
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(
-                [
-                    Constraint::Length(3),
-                    Constraint::Length(3),
-                    Constraint::Length(3),
-                    Constraint::Length(3),
-                    Constraint::Length(3),
-                ]
-                .as_ref(),
-            )
-            .split(area);
-
-        let title = Paragraph::new(self.title.as_str())
-            .style(if self.active_field == 0 {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default()
-            })
-            .block(Block::default().title("Tytuł")
-            .borders(Borders::ALL)
-            .border_style(if self.active_field == 0 {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default()
-            })
-        );
-        f.render_widget(title, chunks[0]);
-
-        let start = Paragraph::new(self.start.as_str())
-            .style(if self.active_field == 1 {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default()
-            })
-            .block(Block::default().title("Data rozpoczęcia (RRRR-MM-DD HH:MM)")
-            .borders(Borders::ALL)
-            .border_style(if self.active_field == 1 {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default()
-            })
-        );
-        f.render_widget(start, chunks[1]);
+#[derive(Clone, Copy, PartialEq)]
+enum TextAction {
+    FreeSlots,
+    Search,
+    Query,
+}
 
-        let duration = Paragraph::new(self.duration.as_str())
-            .style(if self.active_field == 2 {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default()
-            })
-            .block(Block::default().title("Czas trwania (np. 1.5 lub 1:30)")
-            .borders(Borders::ALL)
-            .border_style(if self.active_field == 2 {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default()
-            })
-        );
-        f.render_widget(duration, chunks[2]);
+#[derive(Clone, Copy)]
+enum PromptAction {
+    File(FileAction),
+    Text(TextAction),
+}
 
-        let color = Paragraph::new(self.color.as_str())
-            .style(if self.active_field == 3 {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default()
-            })
-            .block(Block::default().title("Kolor (opcjonalnie)")
-            .borders(Borders::ALL)
-            .border_style(if self.active_field == 3 {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default()
-            })
-        );
-        f.render_widget(color, chunks[3]);
+struct InputPrompt {
+    action: PromptAction,
+    input: String,
+}
 
-        let recurrence = Paragraph::new(self.recurrence.as_str())
-            .style(if self.active_field == 4 {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default()
-            })
-            .block(Block::default().title("Powtarzanie (None/Daily/Weekly/etc.)")
-            .borders(Borders::ALL)
-            .border_style(if self.active_field == 4 {
-                Style::default().fg(Color::Yellow)
-            } else {
-                Style::default()
-            })
-        );
-        f.render_widget(recurrence, chunks[4]);
-
-        if let Some(ref msg) = self.error_message {
-            let error_area = Rect {
-                x: area.x,
-                y: area.y + area.height.saturating_sub(3),
-                width: area.width,
-                height: 3,
-            };
-            let paragraph = Paragraph::new(msg.as_str())
-                .style(Style::default().fg(Color::Red))
-                .block(Block::default().title("Błąd").borders(Borders::ALL));
-            f.render_widget(paragraph, error_area);
+impl InputPrompt {
+    fn new(action: PromptAction) -> Self {
+        let input = match action {
+            PromptAction::File(_) => "events.ics",
+            PromptAction::Text(TextAction::FreeSlots) => "1:00",
+            PromptAction::Text(TextAction::Search | TextAction::Query) => "",
+        };
+        Self {
+            action,
+            input: input.to_string(),
         }
     }
 
-    fn handle_input(&mut self, key: KeyCode) {
-        match key {
-            KeyCode::Char(c) => {
-                self.error_message = None;
-                match self.active_field {
-                    0 => self.title.push(c),
-                    1 => self.start.push(c),
-                    2 => self.duration.push(c),
-                    3 => self.color.push(c),
-                    4 => self.recurrence.push(c),
-                    _ => {}
-                }
-            }
-            KeyCode::Backspace => {
-                self.error_message = None;
-                match self.active_field {
-                    0 => { self.title.pop(); }
-                    1 => { self.start.pop(); }
-                    2 => { self.duration.pop(); }
-                    3 => { self.color.pop(); }
-                    4 => { self.recurrence.pop(); }
-                    _ => {}
-                }
-            }
-            KeyCode::Enter => {
-                self.active_field = (self.active_field + 1) % 5;
-            }
-            KeyCode::Up => {
-                if self.active_field > 0 {
-                    self.active_field -= 1;
-                }
-            }
-            KeyCode::Down => {
-                if self.active_field < 4 {
-                    self.active_field += 1;
-                }
-            }
-            _ => {}
+    fn title(&self) -> &'static str {
+        match self.action {
+            PromptAction::File(FileAction::Import) => "Importuj z pliku .ics",
+            PromptAction::File(FileAction::Export) => "Eksportuj do pliku .ics",
+            PromptAction::Text(TextAction::FreeSlots) => "Szukaj wolnych terminów, czas trwania",
+            PromptAction::Text(TextAction::Search) => "Szukaj",
+            PromptAction::Text(TextAction::Query) => "Filtr (np. color:red duration>2h lub @nazwa)",
         }
     }
 
-    fn get_event(&self) -> Option<Event> {
-        let naive = match NaiveDateTime::parse_from_str(&self.start, "%Y-%m-%d %H:%M") {
-            Ok(dt) => dt,
-            Err(_) => return None,
-        };
-
-        let start: DateTime<Local> = match Local.from_local_datetime(&naive).single() {
-            Some(dt) => dt,
-            None => return None,
-        };
+    fn is(&self, action: TextAction) -> bool {
+        matches!(self.action, PromptAction::Text(text) if text == action)
+    }
+}
 
-        let duration_str = self.duration.trim();
+struct FreeSlotPanel {
+    duration: Duration,
+    slots: Vec<Slot>,
+    selected: usize,
+}
 
-        let total_minutes = if let Some((h, m)) = duration_str.split_once(':') {
-            match (h.trim().parse::<i64>(), m.trim().parse::<i64>()) {
-                (Ok(h), Ok(m)) => h * 60 + m,
-                _ => return None,
-            }
-        } else if let Ok(hf) = duration_str.parse::<f64>() {
-            (hf * 60.0).round() as i64
-        } else {
-            return None;
-        };
+impl FreeSlotPanel {
+    fn search(events: &[Event], filter: &EventFilter, duration: Duration, config: &Config, now: DateTime<Local>) -> Self {
+        let today = now.date_naive();
+        let (first, last) = match filter.view_mode {
+            ViewMode::Day | ViewMode::Week | ViewMode::Month => filter.view_mode.range(filter.current_date),
+            _ => None,
+        }
+        .unwrap_or((today, today + Duration::days(6)));
+        let from = start_of_day(None, first).max(now);
+        let to = start_of_day(None, last + Duration::days(1));
 
-        if total_minutes <= 0 {
-            return None;
+        Self {
+            duration,
+            slots: free_slots(events, from, to, duration, &config.working_hours),
+            selected: 0,
         }
+    }
 
-        let end = start + Duration::minutes(total_minutes);
+    fn render(&self, f: &mut Frame, area: Rect) {
+        let width = area.width.min(60);
+        let height = (self.slots.len() as u16 + 2).min(area.height.saturating_sub(4)).max(3);
+        let popup = Rect::new(
+            area.x + (area.width - width) / 2,
+            area.y + (area.height.saturating_sub(height)) / 2,
+            width,
+            height,
+        );
 
-        let color = if self.color.is_empty() {
-            None
-        } else {
-            Some(self.color.clone())
-        };
+        let items: Vec<ListItem> = self.slots.iter().map(|slot| ListItem::new(format_slot(*slot))).collect();
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .title("Wolne terminy | [Enter] Nowe wydarzenie | [Esc] Zamknij")
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow)),
+            )
+            .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+            .highlight_symbol(">> ");
+        let mut state = ListState::default();
+        state.select(Some(self.selected));
 
-        let recurrence = match self.recurrence.to_lowercase().as_str() {
-            "daily" => Recurrence::Daily,
-            "weekly" => Recurrence::Weekly,
-            "biweekly" => Recurrence::Biweekly,
-            "monthly" => Recurrence::Monthly,
-            "yearly" => Recurrence::Yearly,
-            _ => Recurrence::None,
+        f.render_widget(Clear, popup);
+        f.render_stateful_widget(list, popup, &mut state);
+    }
+}
+
+enum ScopeAction {
+    Edit,
+    Delete,
+}
+
+struct ScopePrompt {
+    action: ScopeAction,
+    occurrence: Event,
+}
+
+impl ScopePrompt {
+    fn text(&self) -> String {
+        let action = match self.action {
+            ScopeAction::Edit => "Edytuj",
+            ScopeAction::Delete => "Usuń",
         };
+        format!(
+            "{} wydarzenie cykliczne: [1] {} [2] {} [3] {} [Esc] Anuluj",
+            action,
+            EditScope::Single.label(),
+            EditScope::Following.label(),
+            EditScope::All.label()
+        )
+    }
+}
+
+struct EditTarget {
+    id: Uuid,
+    occurrence_start: DateTime<Local>,
+    scope: EditScope,
+}
 
-        Some(Event {
-            title: self.title.clone(),
-            start,
-            end,
-            color,
-            recurrence,
-        })
+fn event_style(event: &Event) -> Style {
+    match event.color {
+        Some(ref color) => Style::default().fg(parse_color(color)),
+        None => Style::default(),
     }
+}
 
-    fn from_event(event: &Event) -> Self {
-        Self {
-            title: event.title.clone(),
-            start: event.start.format("%Y-%m-%d %H:%M").to_string(),
-            duration: format!(
-                "{}:{:02}",
-                (event.end - event.start).num_minutes() / 60,
-                (event.end - event.start).num_minutes() % 60
-            ),
-            color: event.color.clone().unwrap_or_default(),
-            recurrence: format!("{:?}", event.recurrence),
-            active_field: 0,
-            error_message: None,
+fn highlight_matches(text: &str, query: Option<&SearchQuery>) -> Vec<Span<'static>> {
+    let Some(query) = query else {
+        return vec![Span::raw(text.to_string())];
+    };
+
+    let mut spans = Vec::new();
+    let mut last = 0;
+    for range in query.find(text) {
+        if range.start > last {
+            spans.push(Span::raw(text[last..range.start].to_string()));
         }
+        spans.push(Span::styled(text[range.clone()].to_string(), Style::default().add_modifier(Modifier::REVERSED)));
+        last = range.end;
     }
+    if last < text.len() {
+        spans.push(Span::raw(text[last..].to_string()));
+    }
+    spans
+}
 
-    fn validate(&self) -> Result<(), String> {
-        if self.title.trim().is_empty() {
-            return Err("Tytuł nie może być pusty.".to_string());
-        }
+fn find_match(events: &[Event], query: &SearchQuery, from: usize, forward: bool, skip_current: bool) -> Option<usize> {
+    let len = events.len();
+    let first = skip_current as usize;
+    (first..len + first)
+        .map(|offset| if forward { (from + offset) % len } else { (from + 2 * len - offset) % len })
+        .find(|index| query.matches(&events[*index]))
+}
 
-        if NaiveDateTime::parse_from_str(&self.start, "%Y-%m-%d %H:%M").is_err() {
-            return Err("Niepoprawny format daty rozpoczęcia.".to_string());
-        }
+#[derive(Default)]
+struct EventList {
+    store: OccurrenceStore,
+    key: Option<(EventFilter, bool, Option<i64>, Option<SearchQuery>)>,
+    events: Vec<Event>,
+    conflicts: HashSet<OccurrenceKey>,
+    match_count: usize,
+    strip_len: usize,
+    strip: List<'static>,
+    timed: List<'static>,
+}
 
-        let duration_str = self.duration.trim();
+impl EventList {
+    fn invalidate(&mut self) {
+        self.store.invalidate();
+        self.key = None;
+    }
 
-        let total_minutes = if let Some((h, m)) = duration_str.split_once(':') {
-            match (h.trim().parse::<i64>(), m.trim().parse::<i64>()) {
-                (Ok(h), Ok(m)) => h * 60 + m,
-                _ => return Err("Czas trwania musi mieć poprawny format (np. 1:30 lub 1.5).".to_string()),
-            }
-        } else if let Ok(hf) = duration_str.parse::<f64>() {
-            (hf * 60.0).round() as i64
+    fn refresh(
+        &mut self,
+        events: &[Event],
+        filter: &EventFilter,
+        sort_asc: bool,
+        query: Option<&SearchQuery>,
+        now: DateTime<Local>,
+    ) {
+        let key = (
+            filter.clone(),
+            sort_asc,
+            filter.show_only_upcoming.then(|| now.timestamp() / 60),
+            query.cloned(),
+        );
+        if self.key.as_ref() == Some(&key) {
+            return;
+        }
+
+        let (from, to) = filter.window(now);
+        let occurrences = self.store.between(events, from, to);
+        let conflicts = overlapping(occurrences.iter().copied());
+        let mut visible: Vec<Event> = visible_occurrences(occurrences, filter, now, sort_asc)
+            .into_iter()
+            .cloned()
+            .collect();
+        self.strip_len = if matches!(filter.view_mode, ViewMode::Day | ViewMode::Week | ViewMode::Month) {
+            visible.sort_by_key(|e| !e.all_day);
+            visible.iter().take_while(|e| e.all_day).count()
         } else {
-            return Err("Czas trwania musi mieć poprawny format (np. 1:30 lub 1.5).".to_string());
+            0
         };
 
-        if total_minutes <= 0 {
-            return Err("Czas trwania musi być większy niż 0.".to_string());
-        }
+        let (all_day_events, timed_events) = visible.split_at(self.strip_len);
+        let strip_items: Vec<ListItem> = all_day_events.iter().map(|e| {
+            let dates = if e.day_count() == 1 {
+                e.start_date().format("%Y-%m-%d").to_string()
+            } else {
+                format!("{} - {}", e.start_date().format("%Y-%m-%d"), e.end_date().format("%Y-%m-%d"))
+            };
+            let mut spans = vec![Span::raw(format!("{}  ", dates))];
+            spans.extend(highlight_matches(&e.title, query));
+            ListItem::new(Line::from(spans)).style(event_style(e))
+        }).collect();
+        let timed_items: Vec<ListItem> = timed_events.iter().map(|e| {
+            let marker = if conflicts.contains(&occurrence_key(e)) { "⚠ " } else { "" };
+            let mut spans = vec![Span::raw(marker)];
+            spans.extend(highlight_matches(&e.title, query));
+            ListItem::new(vec![Line::from(spans), Line::raw(format_event_time(e))]).style(event_style(e))
+        }).collect();
+        self.strip = event_list("Cały dzień", strip_items);
+        self.timed = event_list("Wydarzenia", timed_items);
+
+        self.match_count = query.map_or(0, |q| visible.iter().filter(|e| q.matches(e)).count());
+        self.events = visible;
+        self.conflicts = conflicts;
+        self.key = Some(key);
+    }
+}
 
-        let allowed = ["none", "daily", "weekly", "biweekly", "monthly", "yearly"];
-        if !self.recurrence.trim().is_empty()
-            && !allowed.contains(&self.recurrence.to_lowercase().as_str()) {
-                return Err("Niepoprawna wartość pola 'Powtarzanie'.".to_string());
-        }
+fn event_list(title: &'static str, items: Vec<ListItem<'static>>) -> List<'static> {
+    List::new(items)
+        .block(Block::default().title(title).borders(Borders::ALL))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(">> ")
+}
 
-        Ok(())
+fn is_grid(view_mode: ViewMode) -> bool {
+    matches!(view_mode, ViewMode::Month | ViewMode::Year)
+}
+
+fn render_load_error(f: &mut Frame, area: Rect, err: &StorageError, note: Option<&str>) {
+    let mut text = format!(
+        "{}\n\nPlik nie zostanie nadpisany bez Twojej zgody.\n\n\
+         [r] Wczytaj ponownie (po ręcznej naprawie pliku)\n\
+         [p] Przywróć najnowszą czytelną kopię zapasową\n\
+         [b] Utwórz kopię zapasową pliku i zacznij od pustego kalendarza\n\
+         [q] Wyjdź bez zmian",
+        err
+    );
+    if let Some(note) = note {
+        text.push_str(&format!("\n\n{}", note));
     }
+    let paragraph = Paragraph::new(text)
+        .wrap(Wrap { trim: false })
+        .style(Style::default().fg(Color::Red))
+        .block(Block::default().title("Błąd odczytu wydarzeń").borders(Borders::ALL));
+    f.render_widget(paragraph, area);
 }
 
-pub fn run_ui(events: &mut Vec<Event>) -> Result<(), Box<dyn std::error::Error>> {
+pub fn run_ui(
+    events: &mut Vec<Event>,
+    storage: &Storage,
+    config: &Config,
+    mut load_error: Option<StorageError>,
+) -> Result<(), Box<dyn std::error::Error>> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
@@ -299,295 +350,552 @@ pub fn run_ui(events: &mut Vec<Event>) -> Result<(), Box<dyn std::error::Error>>
     let mut terminal = Terminal::new(backend)?;
     terminal.hide_cursor()?;
 
-    #[derive(PartialEq)]
-    enum ViewMode {
-        All,
-        Week,
-        Month,
-        Year,
-    }
-
     let mut show_form = false;
     let mut form = EventForm::new();
     let mut selected: usize = 0;
-    let mut edit_index: Option<usize> = None;
+    let mut edit_target: Option<EditTarget> = None;
     let mut sort_asc = true;
-    let mut show_only_upcoming = false;
-    let mut filter_color: Option<String> = None;
-    let mut view_mode = ViewMode::All;
-    let mut current_date = Local::now().date_naive();
+    let mut filter = EventFilter::new(Local::now().date_naive());
+    let mut prompt: Option<InputPrompt> = None;
+    let mut free_panel: Option<FreeSlotPanel> = None;
+    let mut search: Option<SearchQuery> = None;
+    let mut show_details = true;
+    let mut scope_prompt: Option<ScopePrompt> = None;
+    let mut status_message: Option<String> = None;
+    let mut list = EventList::default();
+    let mut day_slot_minutes = 30;
+    let mut heat_by_hours = false;
+    let mut extended_above: Option<usize> = None;
 
     loop {
         let now = Local::now();
-        let future_limit = Local::now() + Duration::weeks(200);
-        let mut expanded: Vec<Event> = Vec::new();
-
-        let mut index_map: Vec<(usize, Event)> = Vec::new();
-        for (idx, event) in events.iter().enumerate() {
-            let occurrences = event.generate_occurrences(future_limit);
-            index_map.extend(occurrences.into_iter().map(|e| (idx, e)));
-        }
-
-        let visible_events: Vec<(usize, &Event)> = index_map
-            .iter()
-            .enumerate()
-            .filter(|(_, (_, e))| {
-                let matches_view = match view_mode {
-                    ViewMode::All => true,
-                    ViewMode::Week => {
-                        let week_start = current_date
-                            - chrono::Duration::days(current_date.weekday().num_days_from_monday() as i64);
-                        let week_end = week_start + chrono::Duration::days(6);
-                        e.start.date_naive() >= week_start && e.start.date_naive() <= week_end
-                    }
-                    ViewMode::Month => {
-                        e.start.year() == current_date.year()
-                            && e.start.month() == current_date.month()
-                    }
-                    ViewMode::Year => e.start.year() == current_date.year(),
-                };
-
-                matches_view
-                    && (!show_only_upcoming || e.start >= now)
-                    && (filter_color.is_none() || e.color.as_deref() == filter_color.as_deref())
-                
-            })
-            .map(|(_, (original_index, e))| (*original_index, e))
-            .collect();
-
-        let mut visible_events = visible_events;
-        visible_events.sort_by_key(|(_, e)| e.start);
-        if !sort_asc {
-            visible_events.reverse();
+        list.refresh(events, &filter, sort_asc, search.as_ref(), now);
+        if let Some(previous_len) = extended_above.take() {
+            selected = (list.events.len() - previous_len).saturating_sub(1);
         }
+        let strip_len = list.strip_len;
 
         terminal.draw(|f| {
             let size = f.area();
 
-            if show_form {
+            if let Some(ref err) = load_error {
+                render_load_error(f, size, err, status_message.as_deref());
+            } else if show_form {
                 let form_area = Rect::new(0, 0, size.width, size.height);
                 form.render(f, form_area);
             } else {
-                let items: Vec<ListItem> = visible_events.iter().map(|(_, e)| {
-                    let style = if let Some(ref c) = e.color {
-                        Style::default().fg(parse_color(c))
-                    } else {
-                        Style::default()
-                    };
-
-                    ListItem::new(format!(
-                        "{}\n{} - {}\n",
-                        e.title,
-                        e.start.format("%Y-%m-%d %H:%M"),
-                        e.end.format("%Y-%m-%d %H:%M")
-                    )).style(style)
-                }).collect();
-
+                let current = selected.min(list.events.len().saturating_sub(1));
+                let mut strip_state = ListState::default();
                 let mut state = ListState::default();
-                if !visible_events.is_empty() {
-                    state.select(Some(selected.min(visible_events.len() - 1)));
+                if current < strip_len {
+                    strip_state.select(Some(current));
+                } else if !list.timed.is_empty() {
+                    state.select(Some(current - strip_len));
                 }
 
-                let list = List::new(items)
-                    .block(Block::default().title("Wydarzenia").borders(Borders::ALL))
-                    .highlight_style(
-                        Style::default()
-                            .fg(Color::Yellow)
-                            .add_modifier(Modifier::BOLD),
-                    )
-                    .highlight_symbol(">> ");
-
                 let info = Paragraph::new(format!(
                     "[s] Sortowanie: {} | [f] Filtrowanie: {} | [c] Kolor: {} | [v] Widok: {}",
                     if sort_asc { "Rosnąco" } else { "Malejąco" },
-                    if show_only_upcoming { "Tylko nadchodzące" } else { "Wszystkie" },
-                    filter_color.clone().unwrap_or("Wszystkie".to_string()),
-                    match view_mode {
-                        ViewMode::All => "Wszystko",
-                        ViewMode::Week => "Tydzień",
-                        ViewMode::Month => "Miesiąc",
-                        ViewMode::Year => "Rok",
-                    }
+                    if filter.show_only_upcoming { "Tylko nadchodzące" } else { "Wszystkie" },
+                    filter.color.clone().unwrap_or("Wszystkie".to_string()),
+                    filter.view_mode.label()
                 ))
                 .style(Style::default().fg(Color::Gray));
                 f.render_widget(info, Rect::new(0, 0, size.width, 1));
 
-                let view_info = Paragraph::new(match view_mode {
-                    ViewMode::All => "Widok: Ogólny".to_string(),
-                    ViewMode::Week => {
-                        let start = current_date - chrono::Duration::days(current_date.weekday().num_days_from_monday() as i64);
-                        let end = start + chrono::Duration::days(6);
-                        format!("Widok: Tydzień {} - {}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d"))
-                    }
-                    ViewMode::Month => format!("Widok: Miesiąc {}", current_date.format("%Y-%m")),
-                    ViewMode::Year => format!("Widok: Rok {}", current_date.format("%Y")),
-                })
+                let view_description = match filter.view_mode {
+                    ViewMode::Day => format!(
+                        "{} | Skala: {} min | [z] Zmień skalę | [t] Dziś",
+                        filter.view_mode.describe(filter.current_date),
+                        day_slot_minutes
+                    ),
+                    ViewMode::All => format!(
+                        "{} {} - {}",
+                        filter.view_mode.describe(filter.current_date),
+                        filter.all_range.0.format("%Y-%m-%d"),
+                        filter.all_range.1.format("%Y-%m-%d")
+                    ),
+                    _ => filter.view_mode.describe(filter.current_date),
+                };
+                let view_description = match filter.query {
+                    Some(ref query) => format!("{} | Filtr: {}", view_description, query.text()),
+                    None => view_description,
+                };
+                let view_description = match search {
+                    Some(ref query) => format!(
+                        "{} | Szukaj: \"{}\" | [n] Następne | [N] Poprzednie",
+                        view_description,
+                        query.text()
+                    ),
+                    None => view_description,
+                };
+                let view_info = Paragraph::new(view_description)
                 .style(Style::default().fg(Color::Blue));
 
                 f.render_widget(view_info, Rect::new(0, 1, size.width, 1));
 
-                let list_area = Rect::new(0, 2, size.width, size.height - 2);
-                f.render_stateful_widget(list, list_area, &mut state);
+                let highlighted = list.events
+                    .get(selected.min(list.events.len().saturating_sub(1)))
+                    .filter(|_| !is_grid(filter.view_mode));
+                let mut details: Vec<String> = Vec::new();
+                if let Some(event) = highlighted {
+                    if list.conflicts.contains(&occurrence_key(event)) {
+                        details.push("⚠ Nakłada się na inne wydarzenie".to_string());
+                    }
+                    if let Some(time) = format_original_time(event.start, event.time_zone) {
+                        details.push(format!("Czas w strefie wydarzenia: {}", time));
+                    }
+                }
+
+                let footer = match (&prompt, &scope_prompt, &status_message) {
+                    (Some(p), _, _) => {
+                        let text = if p.is(TextAction::Search) {
+                            format!("{}: {} (pasujące: {})", p.title(), p.input, list.match_count)
+                        } else {
+                            format!("{}: {}", p.title(), p.input)
+                        };
+                        Some(Paragraph::new(text).style(Style::default().fg(Color::Yellow)))
+                    }
+                    (None, Some(p), _) => Some(Paragraph::new(p.text()).style(Style::default().fg(Color::Yellow))),
+                    (None, None, Some(msg)) => {
+                        Some(Paragraph::new(msg.as_str()).style(Style::default().fg(Color::Green)))
+                    }
+                    (None, None, None) if !details.is_empty() => {
+                        Some(Paragraph::new(details.join(" | ")).style(Style::default().fg(Color::Gray)))
+                    }
+                    (None, None, None) => None,
+                };
+                let footer_height = if footer.is_some() { 1 } else { 0 };
+
+                if is_grid(filter.view_mode) {
+                    let grid_area = Rect::new(0, 2, size.width, size.height.saturating_sub(2 + footer_height));
+                    if filter.view_mode == ViewMode::Year {
+                        render_year(f, grid_area, filter.current_date, now.date_naive(), &list.events, heat_by_hours);
+                    } else {
+                        render_month(f, grid_area, filter.current_date, now.date_naive(), &list.events, search.as_ref());
+                    }
+                } else {
+                    let details_width = if show_details && size.width >= 80 { (size.width / 3).min(50) } else { 0 };
+                    let content_width = size.width - details_width;
+                    if details_width > 0 {
+                        let details_area = Rect::new(content_width, 2, details_width, size.height.saturating_sub(2 + footer_height));
+                        render_details(f, details_area, list.events.get(current), search.as_ref());
+                    }
+
+                    let strip_height = if strip_len > 0 { strip_len.min(4) as u16 + 2 } else { 0 };
+                    if strip_height > 0 {
+                        f.render_stateful_widget(&list.strip, Rect::new(0, 2, content_width, strip_height), &mut strip_state);
+                    }
+
+                    let list_top = 2 + strip_height;
+                    let list_area = Rect::new(0, list_top, content_width, size.height.saturating_sub(list_top + footer_height));
+                    let highlight = Highlight { selected: list.events.get(current), query: search.as_ref() };
+                    if filter.view_mode == ViewMode::Day {
+                        let days = [filter.current_date];
+                        render_timeline(f, list_area, &days, day_slot_minutes, &list.events, &highlight, now);
+                    } else if filter.view_mode == ViewMode::Week {
+                        let (week_start, _) = week_bounds(filter.current_date);
+                        let days: Vec<NaiveDate> = week_start.iter_days().take(7).collect();
+                        render_timeline(f, list_area, &days, 60, &list.events, &highlight, now);
+                    } else {
+                        f.render_stateful_widget(&list.timed, list_area, &mut state);
+                    }
+                }
+
+                if let Some(footer) = footer {
+                    f.render_widget(footer, Rect::new(0, size.height.saturating_sub(1), size.width, 1));
+                }
+
+                if let Some(ref panel) = free_panel {
+                    panel.render(f, size);
+                }
             }
         })?;
 
-        if let event::Event::Key(key_event) = event::read()? {
-            if key_event.kind == KeyEventKind::Press {
-                let key = key_event.code;
-
-                if show_form {
-                    match key {
-                        KeyCode::Esc => {
-                            show_form = false;
-                            form = EventForm::new();
-                            edit_index = None;
+        if !event::poll(std::time::Duration::from_secs(30))? {
+            continue;
+        }
+        let key_event = match event::read()? {
+            event::Event::Key(key_event) if key_event.kind == KeyEventKind::Press => key_event,
+            _ => continue,
+        };
+        let key = key_event.code;
+        status_message = None;
+
+        if load_error.is_some() {
+            match key {
+                KeyCode::Char('q') | KeyCode::Esc => break,
+                KeyCode::Char('r') => match storage.load() {
+                    Ok(loaded) => {
+                        *events = loaded;
+                        list.invalidate();
+                        load_error = None;
+                    }
+                    Err(err) => load_error = Some(err),
+                },
+                KeyCode::Char('p') => match storage.backups().into_iter().find(|b| b.event_count.is_some()) {
+                    Some(backup) => match storage.restore(backup.number) {
+                        Ok(restored) => {
+                            *events = restored;
+                            list.invalidate();
+                            load_error = None;
+                            status_message = Some(format!(
+                                "Przywrócono kopię nr {} ({} wydarzeń).",
+                                backup.number,
+                                events.len()
+                            ));
                         }
-                        KeyCode::Enter => {
-                            if form.active_field == 4 {
-                                match form.validate() {
-                                    Ok(()) => {
-                                        if let Some(event) = form.get_event() {
-                                            if let Some(index) = edit_index {
-                                                events[index] = event;
-                                                edit_index = None;
-                                            } else {
-                                                events.push(event);
-                                                selected = events.len().saturating_sub(1);
-                                            }
-                                            crate::storage::save_events(events)?;
-                                            show_form = false;
-                                            form = EventForm::new();
-                                        } else {
-                                            form.error_message = Some("Nie udało się utworzyć wydarzenia.".to_string());
-                                        }
-                                    }
-                                    Err(msg) => {
-                                        form.error_message = Some(msg);
-                                    }
-                                }
+                        Err(err) => load_error = Some(err),
+                    },
+                    None => status_message = Some("Brak czytelnych kopii zapasowych.".to_string()),
+                },
+                KeyCode::Char('b') => match storage.backup_unreadable_file() {
+                    Ok(backup) => {
+                        events.clear();
+                        list.invalidate();
+                        load_error = None;
+                        status_message = Some(format!("Kopia uszkodzonego pliku: {}", backup.display()));
+                    }
+                    Err(err) => load_error = Some(err),
+                },
+                _ => {}
+            }
+        } else if let Some(ref mut p) = prompt {
+            match key {
+                KeyCode::Esc if p.is(TextAction::Search) => {
+                    search = None;
+                    prompt = None;
+                }
+                KeyCode::Esc => prompt = None,
+                KeyCode::Enter => {
+                    let input = p.input.trim();
+                    match p.action {
+                        PromptAction::Text(TextAction::Query) => {
+                            let query = if input.is_empty() {
+                                Ok(None)
+                            } else if let Some(name) = input.strip_prefix('@') {
+                                config.named_filter(name).map(Some)
                             } else {
-                                form.handle_input(key);
+                                FilterQuery::parse(input).map(Some)
+                            };
+                            match query {
+                                Ok(query) => {
+                                    filter.query = query;
+                                    selected = 0;
+                                }
+                                Err(msg) => status_message = Some(format!("Błąd: {}", msg)),
                             }
                         }
-                        _ => {
-                            form.handle_input(key);
-                        }
-                    }
-                } else {
-                    match key {
-                        KeyCode::Char('q') => break,
-                        KeyCode::Char('a') => {
-                            show_form = true;
+                        PromptAction::Text(TextAction::Search) => {
+                            if search.is_some() && list.match_count == 0 {
+                                status_message = Some("Brak pasujących wydarzeń.".to_string());
+                            }
                         }
-                        KeyCode::Char('d') => {
-                            if !visible_events.is_empty() && selected < visible_events.len() {
-                                let index_in_events = visible_events[selected].0;
-                                let recurring = events[index_in_events].recurrence != Recurrence::None;
-
-                                if recurring {
-                                    let event_to_remove = events[index_in_events].clone();
-                                    events.retain(|e| e != &event_to_remove);
+                        PromptAction::Text(TextAction::FreeSlots) => match parse_duration(input) {
+                            Ok(duration) => {
+                                let panel = FreeSlotPanel::search(events, &filter, duration, config, now);
+                                if panel.slots.is_empty() {
+                                    status_message = Some("Brak wolnych terminów w podanym zakresie.".to_string());
                                 } else {
-                                    events.remove(index_in_events);
-                                }
-
-                                crate::storage::save_events(events)?;
-                                if events.is_empty() {
-                                    selected = 0;
-                                } else if selected >= visible_events.len() {
-                                    selected = visible_events.len().saturating_sub(1);
+                                    free_panel = Some(panel);
                                 }
                             }
+                            Err(msg) => status_message = Some(format!("Błąd: {}", msg)),
+                        },
+                        PromptAction::File(action) => {
+                            status_message = Some(match action.execute(Path::new(input), events, storage) {
+                                Ok(msg) => msg,
+                                Err(err) => format!("Błąd: {}", err),
+                            });
+                            list.invalidate();
                         }
-                        KeyCode::Char('e') => {
-                            if !visible_events.is_empty() && selected < visible_events.len() {
-                                let index_in_events = visible_events[selected].0;
-                                let original_event = &events[index_in_events];
-
-                                form = EventForm::from_event(original_event);
-                                show_form = true;
-                                edit_index = Some(index_in_events);
-                            }
+                    }
+                    prompt = None;
+                }
+                KeyCode::Backspace | KeyCode::Char(_) => {
+                    match key {
+                        KeyCode::Char(c) => p.input.push(c),
+                        _ => {
+                            p.input.pop();
                         }
-                        KeyCode::Char('s') => {
-                            sort_asc = !sort_asc;
+                    }
+                    if p.is(TextAction::Search) {
+                        search = SearchQuery::new(&p.input);
+                        if let Some(ref query) = search
+                            && let Some(index) = find_match(&list.events, query, selected, true, false)
+                        {
+                            selected = index;
+                            if is_grid(filter.view_mode) {
+                                filter.current_date = list.events[index].start_date();
+                            }
                         }
-                        KeyCode::Char('f') => {
-                            show_only_upcoming = !show_only_upcoming;
-                            selected = 0;
+                    }
+                }
+                _ => {}
+            }
+        } else if let Some(ref mut panel) = free_panel {
+            match key {
+                KeyCode::Esc => free_panel = None,
+                KeyCode::Up if panel.selected > 0 => panel.selected -= 1,
+                KeyCode::Down if panel.selected + 1 < panel.slots.len() => panel.selected += 1,
+                KeyCode::Enter => {
+                    let (start, _) = panel.slots[panel.selected];
+                    form = EventForm::from_slot(start, panel.duration);
+                    show_form = true;
+                    free_panel = None;
+                }
+                _ => {}
+            }
+        } else if let Some(ref p) = scope_prompt {
+            let scope = match key {
+                KeyCode::Char('1') => Some(EditScope::Single),
+                KeyCode::Char('2') => Some(EditScope::Following),
+                KeyCode::Char('3') => Some(EditScope::All),
+                _ => None,
+            };
+
+            if let Some(scope) = scope {
+                let occurrence = &p.occurrence;
+                match p.action {
+                    ScopeAction::Edit => {
+                        let source = match scope {
+                            EditScope::All => events.iter().find(|e| e.id == occurrence.id),
+                            _ => Some(occurrence),
+                        };
+                        if let Some(source) = source {
+                            form = EventForm::from_event(source);
+                            show_form = true;
+                            edit_target = Some(EditTarget {
+                                id: occurrence.id,
+                                occurrence_start: occurrence.occurrence_start(),
+                                scope,
+                            });
                         }
-                        KeyCode::Char('c') => {
-                            let mut colors: Vec<String> = events.iter()
-                                .filter_map(|e| e.color.clone())
-                                .collect();
-                            colors.sort();
-                            colors.dedup();
-
-                            if colors.is_empty() {
-                                filter_color = None;
-                            } else {
-                                let current = filter_color.clone();
-                                let next = match current {
-                                    None => Some(colors[0].clone()),
-                                    Some(curr) => {
-                                        let idx = colors.iter().position(|c| *c == curr);
-                                        match idx {
-                                            Some(i) if i + 1 < colors.len() => Some(colors[i + 1].clone()),
-                                            _ => None,
-                                        }
-                                    }
+                    }
+                    ScopeAction::Delete => {
+                        delete_occurrence(events, occurrence.id, occurrence.occurrence_start(), scope);
+                        status_message = storage.save(events).err().map(|err| format!("Błąd: {}", err));
+                        list.invalidate();
+                    }
+                }
+                scope_prompt = None;
+            } else if key == KeyCode::Esc {
+                scope_prompt = None;
+            }
+        } else if show_form {
+            match key {
+                KeyCode::Esc => {
+                    show_form = false;
+                    form = EventForm::new();
+                    edit_target = None;
+                }
+                KeyCode::Enter if form.is_last_field() => {
+                    match form.validate() {
+                        Ok(()) => {
+                            if let Some(event) = form.get_event() {
+                                let conflicts = match form.conflict_warning {
+                                    Some(_) => Vec::new(),
+                                    None => find_conflicts(events, &event, edit_target.as_ref().map(|t| t.id), now),
                                 };
-                                filter_color = next;
-                                selected = 0;
+                                if !conflicts.is_empty() {
+                                    form.set_conflicts(&conflicts);
+                                } else {
+                                    if let Some(target) = edit_target.take() {
+                                        edit_occurrence(events, target.id, target.occurrence_start, target.scope, event);
+                                    } else {
+                                        events.push(event);
+                                        selected = events.len().saturating_sub(1);
+                                    }
+                                    status_message = storage.save(events).err().map(|err| format!("Błąd: {}", err));
+                                    list.invalidate();
+                                    show_form = false;
+                                    form = EventForm::new();
+                                }
+                            } else {
+                                form.error_message = Some("Nie udało się utworzyć wydarzenia.".to_string());
                             }
                         }
-                        KeyCode::Char('v') => {
-                            view_mode = match view_mode {
-                                ViewMode::All => ViewMode::Week,
-                                ViewMode::Week => ViewMode::Month,
-                                ViewMode::Month => ViewMode::Year,
-                                ViewMode::Year => ViewMode::All,
-                            };
-                            selected = 0;
-                        }
-                        KeyCode::Left => {
-                            match view_mode {
-                                ViewMode::Week => current_date -= chrono::Duration::weeks(1),
-                                ViewMode::Month => current_date = current_date.with_day(1).unwrap()
-                                    - chrono::Duration::days(1),
-                                ViewMode::Year => current_date = current_date
-                                    .with_month(1).unwrap()
-                                    .with_day(1).unwrap()
-                                    - chrono::Duration::days(1),
-                                _ => {},
-                            }
+                        Err(msg) => {
+                            form.error_message = Some(msg);
                         }
-                        KeyCode::Right => {
-                            match view_mode {
-                                ViewMode::Week => current_date += chrono::Duration::weeks(1),
-                                ViewMode::Month => current_date = current_date.with_day(1).unwrap()
-                                    + chrono::Duration::days(32),
-                                ViewMode::Year => current_date = current_date
-                                    .with_month(1).unwrap()
-                                    .with_day(1).unwrap()
-                                    + chrono::Duration::days(366),
-                                _ => {}, 
-                            }
+                    }
+                }
+                _ => {
+                    form.handle_input(key);
+                }
+            }
+        } else {
+            match key {
+                KeyCode::Char('q') => break,
+                KeyCode::Char('a') => {
+                    show_form = true;
+                }
+                KeyCode::Char('d') if selected < list.events.len() && !is_grid(filter.view_mode) => {
+                    let occurrence = &list.events[selected];
+                    if occurrence.is_recurring() {
+                        scope_prompt = Some(ScopePrompt { action: ScopeAction::Delete, occurrence: occurrence.clone() });
+                    } else {
+                        delete_occurrence(events, occurrence.id, occurrence.start, EditScope::All);
+                        status_message = storage.save(events).err().map(|err| format!("Błąd: {}", err));
+                        list.invalidate();
+                        if events.is_empty() {
+                            selected = 0;
+                        } else if selected >= list.events.len() {
+                            selected = list.events.len().saturating_sub(1);
                         }
-                        KeyCode::Up => {
-                            if selected > 0 {
-                                selected -= 1;
+                    }
+                }
+                KeyCode::Char('e') if selected < list.events.len() && !is_grid(filter.view_mode) => {
+                    let occurrence = &list.events[selected];
+                    if occurrence.is_recurring() {
+                        scope_prompt = Some(ScopePrompt { action: ScopeAction::Edit, occurrence: occurrence.clone() });
+                    } else if let Some(original_event) = events.iter().find(|e| e.id == occurrence.id) {
+                        form = EventForm::from_event(original_event);
+                        show_form = true;
+                        edit_target = Some(EditTarget {
+                            id: occurrence.id,
+                            occurrence_start: occurrence.start,
+                            scope: EditScope::All,
+                        });
+                    }
+                }
+                KeyCode::Char('i') => {
+                    prompt = Some(InputPrompt::new(PromptAction::File(FileAction::Import)));
+                }
+                KeyCode::Char('x') => {
+                    prompt = Some(InputPrompt::new(PromptAction::File(FileAction::Export)));
+                }
+                KeyCode::Char('o') => {
+                    show_details = !show_details;
+                }
+                KeyCode::Char('F') => {
+                    prompt = Some(InputPrompt {
+                        input: filter.query.as_ref().map(|query| query.text().to_string()).unwrap_or_default(),
+                        ..InputPrompt::new(PromptAction::Text(TextAction::Query))
+                    });
+                }
+                KeyCode::Char('/') => {
+                    prompt = Some(InputPrompt::new(PromptAction::Text(TextAction::Search)));
+                }
+                KeyCode::Char(c @ ('n' | 'N')) => {
+                    if let Some(ref query) = search {
+                        match find_match(&list.events, query, selected, c == 'n', true) {
+                            Some(index) => {
+                                selected = index;
+                                if is_grid(filter.view_mode) {
+                                    filter.current_date = list.events[index].start_date();
+                                }
                             }
+                            None => status_message = Some("Brak pasujących wydarzeń.".to_string()),
                         }
-                        KeyCode::Down => {
-                            if selected + 1 < visible_events.len() {
-                                selected += 1;
+                    }
+                }
+                KeyCode::Char('w') => {
+                    prompt = Some(InputPrompt::new(PromptAction::Text(TextAction::FreeSlots)));
+                }
+                KeyCode::Char('s') => {
+                    sort_asc = !sort_asc;
+                }
+                KeyCode::Char('f') => {
+                    filter.show_only_upcoming = !filter.show_only_upcoming;
+                    selected = 0;
+                }
+                KeyCode::Char('c') => {
+                    let mut colors: Vec<String> = events.iter()
+                        .filter_map(|e| e.color.clone())
+                        .collect();
+                    colors.sort();
+                    colors.dedup();
+
+                    if colors.is_empty() {
+                        filter.color = None;
+                    } else {
+                        let current = filter.color.clone();
+                        let next = match current {
+                            None => Some(colors[0].clone()),
+                            Some(curr) => {
+                                let idx = colors.iter().position(|c| *c == curr);
+                                match idx {
+                                    Some(i) if i + 1 < colors.len() => Some(colors[i + 1].clone()),
+                                    _ => None,
+                                }
                             }
-                        }
-                        _ => {}
+                        };
+                        filter.color = next;
+                        selected = 0;
+                    }
+                }
+                KeyCode::Char('v') => {
+                    filter.view_mode = filter.view_mode.next();
+                    selected = 0;
+                }
+                KeyCode::Left => {
+                    match filter.view_mode {
+                        ViewMode::Day | ViewMode::Month | ViewMode::Year => filter.current_date -= chrono::Duration::days(1),
+                        ViewMode::Week => filter.current_date -= chrono::Duration::weeks(1),
+                        _ => {},
+                    }
+                }
+                KeyCode::Right => {
+                    match filter.view_mode {
+                        ViewMode::Day | ViewMode::Month | ViewMode::Year => filter.current_date += chrono::Duration::days(1),
+                        ViewMode::Week => filter.current_date += chrono::Duration::weeks(1),
+                        _ => {}, 
+                    }
+                }
+                KeyCode::Char('t') => {
+                    filter.current_date = Local::now().date_naive();
+                    if filter.view_mode == ViewMode::All {
+                        filter.all_range = page_around(filter.current_date);
+                        selected = 0;
                     }
                 }
-            }   
+                KeyCode::Char('z') if filter.view_mode == ViewMode::Day => {
+                    day_slot_minutes = if day_slot_minutes == 30 { 15 } else { 30 };
+                }
+                KeyCode::Char('h') if filter.view_mode == ViewMode::Year => {
+                    heat_by_hours = !heat_by_hours;
+                }
+                KeyCode::Char('m') if filter.view_mode == ViewMode::Year => {
+                    filter.view_mode = ViewMode::Month;
+                }
+                KeyCode::Up if is_grid(filter.view_mode) => {
+                    filter.current_date -= chrono::Duration::weeks(1);
+                }
+                KeyCode::Down if is_grid(filter.view_mode) => {
+                    filter.current_date += chrono::Duration::weeks(1);
+                }
+                KeyCode::PageUp if is_grid(filter.view_mode) => {
+                    let months = if filter.view_mode == ViewMode::Year { 12 } else { 1 };
+                    filter.current_date = filter.current_date - Months::new(months);
+                }
+                KeyCode::PageDown if is_grid(filter.view_mode) => {
+                    let months = if filter.view_mode == ViewMode::Year { 12 } else { 1 };
+                    filter.current_date = filter.current_date + Months::new(months);
+                }
+                KeyCode::Enter if is_grid(filter.view_mode) => {
+                    filter.view_mode = ViewMode::Day;
+                    selected = 0;
+                }
+                KeyCode::Esc if filter.view_mode == ViewMode::Day => {
+                    filter.view_mode = ViewMode::Month;
+                    selected = 0;
+                }
+                KeyCode::Up if filter.view_mode == ViewMode::All && selected == 0 => {
+                    filter.extend_all_range(!sort_asc);
+                    extended_above = Some(list.events.len());
+                }
+                KeyCode::Down if filter.view_mode == ViewMode::All && selected + 1 >= list.events.len() => {
+                    filter.extend_all_range(sort_asc);
+                }
+                KeyCode::Up if selected > 0 => {
+                    selected -= 1;
+                }
+                KeyCode::Down if selected + 1 < list.events.len() => {
+                    selected += 1;
+                }
+                _ => {}
+            }
         }
     }
 
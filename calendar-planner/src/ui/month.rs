@@ -0,0 +1,90 @@
+use ratatui::{
+    prelude::*,
+    widgets::*
+};
+use chrono::{Datelike, Duration, NaiveDate};
+use crate::model::event::Event;
+use crate::model::search::SearchQuery;
+use crate::model::filter::{week_bounds, ViewMode};
+use super::{event_style, highlight_matches, WEEKDAYS};
+
+pub fn render_month(
+    f: &mut Frame,
+    area: Rect,
+    selected: NaiveDate,
+    today: NaiveDate,
+    events: &[Event],
+    query: Option<&SearchQuery>,
+) {
+    let Some((first, last)) = ViewMode::Month.range(selected) else {
+        return;
+    };
+    let (grid_start, _) = week_bounds(first);
+    let weeks = ((last - grid_start).num_days() / 7 + 1) as u32;
+
+    let rows = Layout::vertical(
+        std::iter::once(Constraint::Length(1)).chain((0..weeks).map(|_| Constraint::Ratio(1, weeks))),
+    )
+    .split(area);
+    let columns = |row: Rect| Layout::horizontal([Constraint::Ratio(1, 7); 7]).split(row);
+
+    for (cell, name) in columns(rows[0]).iter().zip(WEEKDAYS) {
+        f.render_widget(Paragraph::new(name).alignment(Alignment::Center).style(Style::default().fg(Color::Gray)), *cell);
+    }
+
+    for (week, row) in rows[1..].iter().enumerate() {
+        for (weekday, cell) in columns(*row).iter().enumerate() {
+            let day = grid_start + Duration::days(week as i64 * 7 + weekday as i64);
+            let block = day_block(day, day.month() == first.month(), day == selected, day == today);
+            render_day(f, *cell, day, block, events, query);
+        }
+    }
+}
+
+fn day_block(day: NaiveDate, in_month: bool, is_selected: bool, is_today: bool) -> Block<'static> {
+    let title_style = if is_today {
+        Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+    } else if in_month {
+        Style::default()
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    let border_style = if is_selected {
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+    Block::default()
+        .borders(Borders::ALL)
+        .border_style(border_style)
+        .title(Span::styled(format!("{:>2}", day.day()), title_style))
+}
+
+fn render_day(f: &mut Frame, area: Rect, day: NaiveDate, block: Block, events: &[Event], query: Option<&SearchQuery>) {
+    let day_events: Vec<&Event> = events
+        .iter()
+        .filter(|e| e.start_date() <= day && e.end_date() >= day)
+        .collect();
+    let capacity = area.height.saturating_sub(2) as usize;
+    let shown = if day_events.len() > capacity { capacity.saturating_sub(1) } else { day_events.len() };
+
+    let mut lines: Vec<Line> = day_events[..shown]
+        .iter()
+        .map(|e| {
+            let mut spans = Vec::new();
+            if !e.all_day && e.start_date() == day {
+                spans.push(Span::raw(format!("{} ", e.start.format("%H:%M"))));
+            }
+            spans.extend(highlight_matches(&e.title, query));
+            Line::from(spans).style(event_style(e))
+        })
+        .collect();
+    if shown < day_events.len() {
+        lines.push(Line::styled(
+            format!("+{} więcej", day_events.len() - shown),
+            Style::default().fg(Color::Gray),
+        ));
+    }
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
@@ -0,0 +1,215 @@
+use ratatui::{
+    prelude::*,
+    widgets::*
+};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate, NaiveTime};
+use crate::model::event::Event;
+use crate::model::search::SearchQuery;
+use crate::utils::color::parse_color;
+use super::{highlight_matches, WEEKDAYS};
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+const LABEL_WIDTH: u16 = 6;
+
+pub struct Highlight<'a> {
+    pub selected: Option<&'a Event>,
+    pub query: Option<&'a SearchQuery>,
+}
+
+struct Segment<'a> {
+    event: &'a Event,
+    first_row: i64,
+    last_row: i64,
+    lane: usize,
+    lanes: usize,
+}
+
+pub fn render_timeline(
+    f: &mut Frame,
+    area: Rect,
+    days: &[NaiveDate],
+    slot_minutes: i64,
+    events: &[Event],
+    highlight: &Highlight,
+    now: DateTime<Local>,
+) {
+    let selected = highlight.selected;
+    if area.height < 2 || area.width <= LABEL_WIDTH || days.is_empty() {
+        return;
+    }
+
+    let visible_rows = (area.height - 1) as i64;
+    let total_rows = MINUTES_PER_DAY / slot_minutes;
+    let columns = Layout::horizontal(vec![Constraint::Ratio(1, days.len() as u32); days.len()])
+        .split(Rect::new(area.x + LABEL_WIDTH, area.y, area.width - LABEL_WIDTH, area.height));
+    let segments: Vec<Vec<Segment>> = days.iter().map(|day| day_segments(*day, slot_minutes, events)).collect();
+
+    let now_row = days
+        .contains(&now.date_naive())
+        .then(|| (now.naive_local().time() - NaiveTime::MIN).num_minutes() / slot_minutes);
+    let selected_rows = segments
+        .iter()
+        .flatten()
+        .find(|s| selected.is_some_and(|e| same_occurrence(s.event, e)))
+        .map(|s| (s.first_row, s.last_row));
+
+    let anchor = now_row
+        .map(|row| row - visible_rows / 3)
+        .or_else(|| segments.iter().flatten().map(|s| s.first_row - 1).min())
+        .unwrap_or(8 * 60 / slot_minutes);
+    let fit = |row: i64| row.min(total_rows - visible_rows).max(0);
+    let mut top_row = fit(anchor);
+    if let Some((first, last)) = selected_rows
+        && (first < top_row || last > top_row + visible_rows)
+    {
+        top_row = fit(first - 1);
+    }
+
+    for row in 0..visible_rows.min(total_rows) {
+        let minutes = (top_row + row) * slot_minutes;
+        if minutes % 60 == 0 {
+            let label = format!("{:02}:00", minutes / 60);
+            let label_area = Rect::new(area.x, area.y + 1 + row as u16, LABEL_WIDTH, 1);
+            f.render_widget(Paragraph::new(label).style(Style::default().fg(Color::Gray)), label_area);
+        }
+    }
+
+    for ((day, column), day_segments) in days.iter().zip(columns.iter()).zip(&segments) {
+        let header_style = if *day == now.date_naive() {
+            Style::default().fg(Color::Black).bg(Color::Cyan).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        let header = format!(
+            "{} {}",
+            WEEKDAYS[day.weekday().num_days_from_monday() as usize],
+            day.format("%d.%m")
+        );
+        f.render_widget(Paragraph::new(header).style(header_style), Rect::new(column.x, column.y, column.width, 1));
+
+        let body = Rect::new(column.x, column.y + 1, column.width, column.height - 1);
+        f.render_widget(
+            Block::default().borders(Borders::LEFT).border_style(Style::default().fg(Color::DarkGray)),
+            body,
+        );
+        let inner = Rect::new(body.x + 1, body.y, body.width.saturating_sub(1), body.height);
+
+        for segment in day_segments {
+            let first = segment.first_row.max(top_row);
+            let last = segment.last_row.min(top_row + visible_rows);
+            if first >= last {
+                continue;
+            }
+
+            let lanes = segment.lanes as u16;
+            let left = inner.width * segment.lane as u16 / lanes;
+            let right = inner.width * (segment.lane as u16 + 1) / lanes;
+            if right <= left {
+                continue;
+            }
+            let block_area = Rect::new(
+                inner.x + left,
+                inner.y + (first - top_row) as u16,
+                right - left,
+                (last - first) as u16,
+            );
+
+            let is_selected = selected.is_some_and(|e| same_occurrence(segment.event, e));
+            let text = vec![
+                Line::from(highlight_matches(&segment.event.title, highlight.query)),
+                Line::from(format!(
+                    "{}-{}",
+                    segment.event.start.format("%H:%M"),
+                    segment.event.end.format("%H:%M")
+                )),
+            ];
+            f.render_widget(Paragraph::new(text).style(block_style(segment.event, is_selected)), block_area);
+        }
+
+        if *day == now.date_naive()
+            && let Some(row) = now_row.filter(|row| (top_row..top_row + visible_rows).contains(row))
+        {
+            let y = inner.y + (row - top_row) as u16;
+            let label_area = Rect::new(area.x, y, LABEL_WIDTH, 1);
+            f.render_widget(Paragraph::new(now.format("%H:%M").to_string()).style(Style::default().fg(Color::Red)), label_area);
+
+            let buffer = f.buffer_mut();
+            for x in inner.x..inner.x + inner.width {
+                let cell = &mut buffer[(x, y)];
+                if cell.symbol() == " " {
+                    cell.set_symbol("─");
+                }
+                cell.set_fg(Color::Red);
+            }
+        }
+    }
+}
+
+fn same_occurrence(a: &Event, b: &Event) -> bool {
+    a.id == b.id && a.occurrence_start() == b.occurrence_start()
+}
+
+fn block_style(event: &Event, is_selected: bool) -> Style {
+    if is_selected {
+        return Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD);
+    }
+    let background = event.color.as_deref().map_or(Color::DarkGray, parse_color);
+    Style::default().fg(Color::Black).bg(background)
+}
+
+fn day_segments(day: NaiveDate, slot_minutes: i64, events: &[Event]) -> Vec<Segment<'_>> {
+    let day_start = day.and_time(NaiveTime::MIN);
+    let day_end = day_start + Duration::days(1);
+
+    let mut segments: Vec<Segment> = events
+        .iter()
+        .filter(|e| !e.all_day)
+        .filter_map(|event| {
+            let start = event.start.naive_local();
+            let end = event.end.naive_local().max(start);
+            if start >= day_end || (end <= day_start && start < day_start) {
+                return None;
+            }
+            let start_minutes = (start.max(day_start) - day_start).num_minutes();
+            let end_minutes = (end.min(day_end) - day_start).num_minutes();
+            let first_row = start_minutes / slot_minutes;
+            let last_row = ((end_minutes + slot_minutes - 1) / slot_minutes).max(first_row + 1);
+            Some(Segment { event, first_row, last_row, lane: 0, lanes: 1 })
+        })
+        .collect();
+    segments.sort_by_key(|s| (s.first_row, std::cmp::Reverse(s.last_row)));
+
+    let mut cluster_start = 0;
+    let mut cluster_end = i64::MIN;
+    let mut lane_ends: Vec<i64> = Vec::new();
+    for index in 0..segments.len() {
+        if segments[index].first_row >= cluster_end {
+            assign_lanes(&mut segments[cluster_start..index], lane_ends.len());
+            cluster_start = index;
+            lane_ends.clear();
+        }
+
+        let segment = &mut segments[index];
+        match lane_ends.iter().position(|end| *end <= segment.first_row) {
+            Some(lane) => {
+                segment.lane = lane;
+                lane_ends[lane] = segment.last_row;
+            }
+            None => {
+                segment.lane = lane_ends.len();
+                lane_ends.push(segment.last_row);
+            }
+        }
+        cluster_end = cluster_end.max(segment.last_row);
+    }
+    let count = segments.len();
+    assign_lanes(&mut segments[cluster_start..count], lane_ends.len());
+
+    segments
+}
+
+fn assign_lanes(cluster: &mut [Segment], lanes: usize) {
+    for segment in cluster {
+        segment.lanes = lanes.max(1);
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use ratatui::{
+    prelude::*,
+    widgets::*
+};
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime};
+use crate::model::event::Event;
+use crate::model::filter::{week_bounds, ViewMode};
+use super::WEEKDAYS;
+
+const MONTHS: [&str; 12] = [
+    "Styczeń", "Luty", "Marzec", "Kwiecień", "Maj", "Czerwiec",
+    "Lipiec", "Sierpień", "Wrzesień", "Październik", "Listopad", "Grudzień",
+];
+const SHADES: [Color; 4] = [Color::Indexed(22), Color::Indexed(28), Color::Indexed(34), Color::Indexed(40)];
+const MONTH_WIDTH: u16 = 23;
+const MONTH_HEIGHT: u16 = 9;
+
+#[derive(Default, Clone, Copy)]
+struct DayLoad {
+    count: usize,
+    minutes: i64,
+}
+
+impl DayLoad {
+    fn level(self, by_hours: bool) -> usize {
+        let thresholds = if by_hours { [1, 60, 180, 360] } else { [1, 2, 3, 5] };
+        let value = if by_hours { self.minutes } else { self.count as i64 };
+        thresholds.iter().filter(|threshold| value >= **threshold).count()
+    }
+}
+
+pub fn render_year(f: &mut Frame, area: Rect, selected: NaiveDate, today: NaiveDate, events: &[Event], by_hours: bool) {
+    let loads = day_loads(events);
+    let columns = (area.width / MONTH_WIDTH).clamp(1, 4);
+    let rows = 12_u16.div_ceil(columns);
+    let visible_rows = (area.height.saturating_sub(1) / MONTH_HEIGHT).clamp(1, rows);
+    let selected_row = selected.month0() as u16 / columns;
+    let first_row = selected_row.saturating_sub(visible_rows - 1);
+
+    for month in first_row * columns..12_u16.min((first_row + visible_rows) * columns) {
+        let x = area.x + (month % columns) * MONTH_WIDTH;
+        let y = area.y + (month / columns - first_row) * MONTH_HEIGHT;
+        let Some(first) = NaiveDate::from_ymd_opt(selected.year(), month as u32 + 1, 1) else {
+            continue;
+        };
+        render_mini_month(f, Rect::new(x, y, MONTH_WIDTH, MONTH_HEIGHT), first, selected, today, &loads, by_hours);
+    }
+
+    let load = loads.get(&selected).copied().unwrap_or_default();
+    let mut legend = vec![Span::raw(format!(
+        "{}: {} wydarzeń, zajęte {}:{:02} h | [h] Cieniowanie: {} | Mniej ",
+        selected.format("%Y-%m-%d"),
+        load.count,
+        load.minutes / 60,
+        load.minutes % 60,
+        if by_hours { "godziny" } else { "liczba" }
+    ))];
+    legend.extend(SHADES.iter().map(|shade| Span::styled("  ", Style::default().bg(*shade))));
+    legend.push(Span::raw(" Więcej | [Enter] Dzień | [m] Miesiąc"));
+    let legend_y = area.y + (visible_rows * MONTH_HEIGHT).min(area.height.saturating_sub(1));
+    f.render_widget(Paragraph::new(Line::from(legend)), Rect::new(area.x, legend_y, area.width, 1));
+}
+
+fn render_mini_month(
+    f: &mut Frame,
+    area: Rect,
+    first: NaiveDate,
+    selected: NaiveDate,
+    today: NaiveDate,
+    loads: &HashMap<NaiveDate, DayLoad>,
+    by_hours: bool,
+) {
+    let Some((_, last)) = ViewMode::Month.range(first) else {
+        return;
+    };
+    let (grid_start, _) = week_bounds(first);
+
+    let mut lines = vec![Line::styled(
+        WEEKDAYS.iter().map(|name| format!("{:<3}", name)).collect::<String>(),
+        Style::default().fg(Color::Gray),
+    )];
+    for week in 0..6 {
+        let spans: Vec<Span> = (0..7)
+            .map(|weekday| {
+                let day = grid_start + Duration::days(week * 7 + weekday);
+                if day < first || day > last {
+                    return Span::raw("   ");
+                }
+
+                let level = loads.get(&day).map_or(0, |load| load.level(by_hours));
+                let mut style = match level {
+                    0 => Style::default(),
+                    level => Style::default().fg(Color::White).bg(SHADES[level - 1]),
+                };
+                if day == today {
+                    style = style.fg(Color::Cyan).add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+                }
+                if day == selected {
+                    style = style.fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD);
+                }
+                Span::styled(format!("{:>2} ", day.day()), style)
+            })
+            .collect();
+        lines.push(Line::from(spans));
+    }
+
+    let title_style = if first.month() == selected.month() {
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::DarkGray))
+        .title(Span::styled(MONTHS[first.month0() as usize], title_style));
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn day_loads(events: &[Event]) -> HashMap<NaiveDate, DayLoad> {
+    let mut loads: HashMap<NaiveDate, DayLoad> = HashMap::new();
+    for event in events {
+        for day in event.start_date().iter_days().take(event.day_count() as usize) {
+            let load = loads.entry(day).or_default();
+            load.count += 1;
+            if !event.all_day {
+                let day_start = day.and_time(NaiveTime::MIN);
+                let start = event.start.naive_local().max(day_start);
+                let end = event.end.naive_local().min(day_start + Duration::days(1));
+                load.minutes += (end - start).num_minutes().max(0);
+            }
+        }
+    }
+    loads
+}
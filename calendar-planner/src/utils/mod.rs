@@ -1 +1,3 @@
-pub mod color;
\ No newline at end of file
+pub mod color;
+pub mod parse;
+pub mod time;
\ No newline at end of file
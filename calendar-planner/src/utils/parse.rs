@@ -0,0 +1,352 @@
+use chrono::{DateTime, Duration, Local, LocalResult, Months, NaiveDate, NaiveDateTime, NaiveTime};
+use chrono_tz::Tz;
+use crate::model::event::Event;
+use crate::model::filter::ViewMode;
+use crate::model::free::Slot;
+use crate::model::recurrence::{parse_numbers, Frequency, MissingDay, Recurrence};
+use crate::utils::time::{format_in, from_wall_clock, is_ambiguous, local_time_zone, resolve_in, wall_clock};
+
+pub const DATE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+pub const DATE_TIME_OFFSET_FORMAT: &str = "%Y-%m-%d %H:%M %:z";
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+const MAX_DURATION_MINUTES: i64 = 366 * 24 * 60;
+
+pub fn parse_start(input: &str) -> Result<DateTime<Local>, String> {
+    parse_start_in(input, None)
+}
+
+pub fn parse_start_in(input: &str, zone: Option<Tz>) -> Result<DateTime<Local>, String> {
+    let trimmed = input.trim();
+    if let Ok(with_offset) = DateTime::parse_from_str(trimmed, DATE_TIME_OFFSET_FORMAT) {
+        return Ok(with_offset.with_timezone(&Local));
+    }
+
+    let naive = NaiveDateTime::parse_from_str(trimmed, DATE_TIME_FORMAT)
+        .map_err(|_| "Niepoprawny format daty rozpoczęcia.".to_string())?;
+
+    match from_wall_clock(zone, naive) {
+        LocalResult::Single(start) => Ok(start),
+        LocalResult::Ambiguous(first, second) => Err(format!(
+            "Godzina {} występuje dwukrotnie (zmiana czasu). Dopisz przesunięcie: {} lub {}.",
+            naive.format(DATE_TIME_FORMAT),
+            format_in(zone, first.min(second), DATE_TIME_OFFSET_FORMAT),
+            format_in(zone, first.max(second), DATE_TIME_OFFSET_FORMAT)
+        )),
+        LocalResult::None => Err(format!(
+            "Godzina {} nie istnieje (zmiana czasu). Najbliższa poprawna godzina to {}.",
+            naive.format(DATE_TIME_FORMAT),
+            format_in(zone, resolve_in(zone, naive), DATE_TIME_FORMAT)
+        )),
+    }
+}
+
+pub fn format_start(start: DateTime<Local>, zone: Option<Tz>) -> String {
+    if is_ambiguous(zone, start) {
+        format_in(zone, start, DATE_TIME_OFFSET_FORMAT)
+    } else {
+        format_in(zone, start, DATE_TIME_FORMAT)
+    }
+}
+
+pub fn format_original_time(time: DateTime<Local>, zone: Option<Tz>) -> Option<String> {
+    let zone = zone?;
+    if Some(zone) == local_time_zone() {
+        return None;
+    }
+    Some(format!("{} {}", time.with_timezone(&zone).format(DATE_TIME_FORMAT), zone.name()))
+}
+
+pub fn parse_time_zone(input: &str) -> Result<Option<Tz>, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+
+    trimmed
+        .parse::<Tz>()
+        .map(Some)
+        .map_err(|_| format!("Nieznana strefa czasowa: {} (np. Europe/Warsaw, America/New_York).", trimmed))
+}
+
+pub fn parse_date(input: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(input.trim(), DATE_FORMAT)
+        .map_err(|_| "Niepoprawny format daty (RRRR-MM-DD).".to_string())
+}
+
+pub fn parse_duration(input: &str) -> Result<Duration, String> {
+    let duration_str = input.trim();
+    let format_error = || "Czas trwania musi mieć poprawny format (np. 1:30 lub 1.5).".to_string();
+
+    let too_long_error = || "Czas trwania nie może przekraczać 366 dni.".to_string();
+
+    let unsigned = |part: &str| {
+        let part = part.trim();
+        (!part.is_empty() && part.bytes().all(|b| b.is_ascii_digit())).then(|| part.parse::<i64>().ok()).flatten()
+    };
+
+    let total_minutes = if let Some((h, m)) = duration_str.split_once(':') {
+        match (unsigned(h), unsigned(m)) {
+            (Some(h), Some(m)) if m < 60 => h.checked_mul(60).and_then(|h| h.checked_add(m)).ok_or_else(too_long_error)?,
+            _ => return Err(format_error()),
+        }
+    } else if let Ok(hf) = duration_str.parse::<f64>() {
+        if !hf.is_finite() {
+            return Err(format_error());
+        }
+        let minutes = (hf * 60.0).round();
+        if minutes > MAX_DURATION_MINUTES as f64 {
+            return Err(too_long_error());
+        }
+        minutes as i64
+    } else {
+        return Err(format_error());
+    };
+
+    if total_minutes <= 0 {
+        return Err("Czas trwania musi być większy niż 0.".to_string());
+    }
+    if total_minutes > MAX_DURATION_MINUTES {
+        return Err(too_long_error());
+    }
+
+    Duration::try_minutes(total_minutes).ok_or_else(too_long_error)
+}
+
+pub fn end_after(start: DateTime<Local>, duration: Duration) -> Result<DateTime<Local>, String> {
+    start
+        .checked_add_signed(duration)
+        .ok_or_else(|| "Koniec wydarzenia wykracza poza obsługiwany zakres dat.".to_string())
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{}:{:02}", duration.num_minutes() / 60, duration.num_minutes() % 60)
+}
+
+pub fn parse_days(input: &str) -> Result<u32, String> {
+    match input.trim().parse::<u32>() {
+        Ok(days) if days > 0 => Ok(days),
+        _ => Err("Liczba dni musi być dodatnią liczbą całkowitą.".to_string()),
+    }
+}
+
+pub fn format_event_time(event: &Event) -> String {
+    if !event.all_day {
+        return format!("{} - {}", event.start.format(DATE_TIME_FORMAT), event.end.format(DATE_TIME_FORMAT));
+    }
+
+    if event.day_count() == 1 {
+        format!("{} (cały dzień)", event.start_date().format(DATE_FORMAT))
+    } else {
+        format!(
+            "{} - {} (cały dzień)",
+            event.start_date().format(DATE_FORMAT),
+            event.end_date().format(DATE_FORMAT)
+        )
+    }
+}
+
+pub fn parse_within(input: &str, today: NaiveDate) -> Result<(NaiveDate, NaiveDate), String> {
+    let trimmed = input.trim();
+    let error = || {
+        format!(
+            "Niepoprawny zakres: {} (today, tomorrow, this-week, next-week, this-month, next-month lub RRRR-MM-DD..RRRR-MM-DD).",
+            trimmed
+        )
+    };
+
+    let range = match trimmed {
+        "today" => Some((today, today)),
+        "tomorrow" => today.succ_opt().map(|day| (day, day)),
+        "this-week" => ViewMode::Week.range(today),
+        "next-week" => ViewMode::Week.range(today + Duration::weeks(1)),
+        "this-month" => ViewMode::Month.range(today),
+        "next-month" => today.checked_add_months(Months::new(1)).and_then(|day| ViewMode::Month.range(day)),
+        _ => {
+            let (first, last) = trimmed.split_once("..").unwrap_or((trimmed, trimmed));
+            Some((parse_date(first).map_err(|_| error())?, parse_date(last).map_err(|_| error())?))
+        }
+    };
+
+    match range {
+        Some((first, last)) if first <= last => Ok((first, last)),
+        _ => Err(error()),
+    }
+}
+
+pub fn format_slot((start, end): Slot) -> String {
+    format!(
+        "{} - {} (wolne {})",
+        start.format(DATE_TIME_FORMAT),
+        end.format("%H:%M"),
+        format_duration(end - start)
+    )
+}
+
+pub fn parse_recurrence(input: &str) -> Result<Option<Recurrence>, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("none") {
+        return Ok(None);
+    }
+    if trimmed.to_uppercase().starts_with("FREQ=") {
+        return trimmed.parse().map(Some);
+    }
+
+    let error = || format!("Niepoprawna wartość pola 'Powtarzanie': {}.", trimmed);
+    let mut tokens = trimmed.split_whitespace();
+    let head = tokens.next().unwrap_or_default().to_lowercase();
+    let (name, interval) = match head.split_once('/') {
+        Some((name, interval)) => (name, Some(interval.parse::<u32>().map_err(|_| error())?)),
+        None => (head.as_str(), None),
+    };
+
+    let mut recurrence = match name {
+        "daily" => Recurrence::new(Frequency::Daily),
+        "weekly" => Recurrence::new(Frequency::Weekly),
+        "biweekly" if interval.is_none() => Recurrence { interval: 2, ..Recurrence::new(Frequency::Weekly) },
+        "monthly" => Recurrence::new(Frequency::Monthly),
+        "yearly" => Recurrence::new(Frequency::Yearly),
+        _ => return Err(error()),
+    };
+    if let Some(interval) = interval {
+        recurrence.interval = interval;
+    }
+
+    for token in tokens {
+        if let Some(missing_day) = MissingDay::from_label(&token.to_lowercase()) {
+            recurrence.missing_day = missing_day;
+            continue;
+        }
+        if let Some(positions) = token.strip_prefix('@') {
+            recurrence.by_set_pos.extend(parse_numbers(positions).ok_or_else(error)?);
+            continue;
+        }
+        for item in token.split(',').filter(|item| !item.is_empty()) {
+            match item.parse::<i32>() {
+                Ok(day) => recurrence.by_month_day.push(day),
+                Err(_) => recurrence.by_day.push(item.parse().map_err(|_| error())?),
+            }
+        }
+    }
+
+    recurrence.validate()?;
+    Ok(Some(recurrence))
+}
+
+pub fn format_recurrence(recurrence: &Recurrence) -> String {
+    let name = match recurrence.frequency {
+        Frequency::Daily => "daily",
+        Frequency::Weekly => "weekly",
+        Frequency::Monthly => "monthly",
+        Frequency::Yearly => "yearly",
+    };
+    let mut parts = vec![match recurrence.interval {
+        1 => name.to_string(),
+        interval => format!("{}/{}", name, interval),
+    }];
+
+    let join = |values: Vec<String>| values.join(",");
+    if !recurrence.by_day.is_empty() {
+        parts.push(join(recurrence.by_day.iter().map(|d| d.to_string().to_lowercase()).collect()));
+    }
+    if !recurrence.by_month_day.is_empty() {
+        parts.push(join(recurrence.by_month_day.iter().map(|d| d.to_string()).collect()));
+    }
+    if !recurrence.by_set_pos.is_empty() {
+        parts.push(format!("@{}", join(recurrence.by_set_pos.iter().map(|p| p.to_string()).collect())));
+    }
+    if recurrence.missing_day != MissingDay::Skip {
+        parts.push(recurrence.missing_day.label().to_string());
+    }
+    parts.join(" ")
+}
+
+pub fn parse_until(input: &str, zone: Option<Tz>) -> Result<Option<DateTime<Local>>, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+
+    if let Ok(date_time) = parse_start_in(trimmed, zone) {
+        return Ok(Some(date_time));
+    }
+
+    let date = parse_date(trimmed)
+        .map_err(|_| "Niepoprawny format daty końca powtarzania (RRRR-MM-DD).".to_string())?;
+    let end_of_day = date.and_hms_opt(23, 59, 59).expect("poprawna godzina");
+    Ok(Some(resolve_in(zone, end_of_day)))
+}
+
+pub fn format_until(until: DateTime<Local>, zone: Option<Tz>) -> String {
+    if Some(wall_clock(zone, until).time()) == NaiveTime::from_hms_opt(23, 59, 59) {
+        format_in(zone, until, DATE_FORMAT)
+    } else {
+        format_in(zone, until, DATE_TIME_FORMAT)
+    }
+}
+
+pub fn parse_count(input: &str) -> Result<Option<u32>, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+
+    match trimmed.parse::<u32>() {
+        Ok(count) if count > 0 => Ok(Some(count)),
+        _ => Err("Liczba wystąpień musi być dodatnią liczbą całkowitą.".to_string()),
+    }
+}
+
+pub fn parse_text(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+pub fn parse_url(input: &str) -> Result<Option<String>, String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        Ok(None)
+    } else if trimmed.contains("://") || trimmed.starts_with("mailto:") {
+        Ok(Some(trimmed.to_string()))
+    } else {
+        Err("Adres URL musi zawierać schemat, np. https://.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_duration_rejects_huge_and_non_finite_input() {
+        assert_eq!(parse_duration("1:30"), Ok(Duration::minutes(90)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::minutes(90)));
+        for input in ["inf", "NaN", "1e20", "99999999999999999:0", "9999999999", "-1", "0:00"] {
+            assert!(parse_duration(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn parse_duration_requires_unsigned_hours_and_minutes_below_sixty() {
+        assert_eq!(parse_duration("0:59"), Ok(Duration::minutes(59)));
+        assert_eq!(parse_duration(" 2 : 05 "), Ok(Duration::minutes(125)));
+        for input in ["1:75", "1:60", "2:-30", "-1:30", "+1:30", "1:+30", "1:", ":30", "1:3.5"] {
+            assert!(parse_duration(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn parse_start_in_rejects_times_changed_by_dst() {
+        let zone = Some(chrono_tz::Europe::Warsaw);
+        let missing = parse_start_in("2026-03-29 02:30", zone).unwrap_err();
+        assert!(missing.starts_with("Godzina 2026-03-29 02:30 nie istnieje"), "{}", missing);
+        assert!(missing.contains("03:30"), "{}", missing);
+
+        let doubled = parse_start_in("2026-10-25 02:30", zone).unwrap_err();
+        assert!(doubled.contains("występuje dwukrotnie"), "{}", doubled);
+        assert!(doubled.contains("+02:00") && doubled.contains("+01:00"), "{}", doubled);
+
+        let earlier = parse_start_in("2026-10-25 02:30 +02:00", zone).unwrap();
+        let later = parse_start_in("2026-10-25 02:30 +01:00", zone).unwrap();
+        assert_eq!(later - earlier, Duration::hours(1));
+    }
+}
@@ -0,0 +1,101 @@
+use chrono::{DateTime, Duration, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use chrono_tz::Tz;
+
+pub fn local_time_zone() -> Option<Tz> {
+    std::env::var("TZ")
+        .ok()
+        .and_then(|name| name.trim_start_matches(':').parse().ok())
+        .or_else(|| iana_time_zone::get_timezone().ok().and_then(|name| name.parse().ok()))
+}
+
+pub fn from_wall_clock(zone: Option<Tz>, naive: NaiveDateTime) -> LocalResult<DateTime<Local>> {
+    match zone {
+        Some(zone) => zone.from_local_datetime(&naive).map(|time| time.with_timezone(&Local)),
+        None => Local.from_local_datetime(&naive),
+    }
+}
+
+pub fn wall_clock(zone: Option<Tz>, time: DateTime<Local>) -> NaiveDateTime {
+    match zone {
+        Some(zone) => time.with_timezone(&zone).naive_local(),
+        None => time.naive_local(),
+    }
+}
+
+pub fn format_in(zone: Option<Tz>, time: DateTime<Local>, format: &str) -> String {
+    match zone {
+        Some(zone) => time.with_timezone(&zone).format(format).to_string(),
+        None => time.format(format).to_string(),
+    }
+}
+
+pub fn resolve_in(zone: Option<Tz>, naive: NaiveDateTime) -> DateTime<Local> {
+    match from_wall_clock(zone, naive) {
+        LocalResult::Single(time) => time,
+        LocalResult::Ambiguous(first, second) => first.min(second),
+        LocalResult::None => skip_gap(zone, naive),
+    }
+}
+
+pub fn start_of_day(zone: Option<Tz>, date: NaiveDate) -> DateTime<Local> {
+    resolve_in(zone, date.and_time(NaiveTime::MIN))
+}
+
+pub fn is_ambiguous(zone: Option<Tz>, time: DateTime<Local>) -> bool {
+    matches!(from_wall_clock(zone, wall_clock(zone, time)), LocalResult::Ambiguous(_, _))
+}
+
+fn skip_gap(zone: Option<Tz>, naive: NaiveDateTime) -> DateTime<Local> {
+    (1..=24)
+        .find_map(|hours| {
+            let before_gap = match from_wall_clock(zone, naive - Duration::hours(hours)) {
+                LocalResult::Single(time) => time,
+                LocalResult::Ambiguous(first, second) => first.max(second),
+                LocalResult::None => return None,
+            };
+            Some(before_gap + Duration::hours(hours))
+        })
+        .unwrap_or_else(|| Local.from_utc_datetime(&naive))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono_tz::{America, Europe};
+
+    fn naive(text: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn in_zone(zone: Tz, time: DateTime<Local>) -> String {
+        time.with_timezone(&zone).format("%Y-%m-%d %H:%M %:z").to_string()
+    }
+
+    #[test]
+    fn nonexistent_time_moves_past_the_gap() {
+        let zone = Some(Europe::Warsaw);
+        assert_eq!(in_zone(Europe::Warsaw, resolve_in(zone, naive("2026-03-29 02:30"))), "2026-03-29 03:30 +02:00");
+        assert_eq!(in_zone(Europe::Warsaw, skip_gap(zone, naive("2026-03-29 02:00"))), "2026-03-29 03:00 +02:00");
+
+        let zone = Some(America::New_York);
+        assert_eq!(in_zone(America::New_York, resolve_in(zone, naive("2026-03-08 02:30"))), "2026-03-08 03:30 -04:00");
+    }
+
+    #[test]
+    fn ambiguous_time_resolves_to_the_earlier_instant() {
+        let zone = Some(Europe::Warsaw);
+        let time = resolve_in(zone, naive("2026-10-25 02:30"));
+        assert_eq!(in_zone(Europe::Warsaw, time), "2026-10-25 02:30 +02:00");
+        assert!(is_ambiguous(zone, time));
+        assert!(is_ambiguous(zone, time + Duration::hours(1)));
+        assert!(!is_ambiguous(zone, resolve_in(zone, naive("2026-10-25 03:30"))));
+    }
+
+    #[test]
+    fn start_of_day_is_midnight_in_the_zone() {
+        let zone = Some(Europe::Warsaw);
+        let date = NaiveDate::from_ymd_opt(2026, 10, 25).unwrap();
+        assert_eq!(in_zone(Europe::Warsaw, start_of_day(zone, date)), "2026-10-25 00:00 +02:00");
+        assert_eq!(wall_clock(zone, start_of_day(zone, date.succ_opt().unwrap())), naive("2026-10-26 00:00"));
+    }
+}